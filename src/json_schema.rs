@@ -0,0 +1,102 @@
+//! `schemars::JsonSchema` support, see the `schemars` feature.
+
+use alloc::{borrow::Cow, format};
+
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// Returns the schema of a non-empty array (`minItems: 1`) of `T`.
+pub(crate) fn nonempty_array_schema<T>(generator: &mut SchemaGenerator) -> Schema
+where
+    T: JsonSchema,
+{
+    json_schema!({
+        "type": "array",
+        "items": generator.subschema_for::<T>(),
+        "minItems": 1,
+    })
+}
+
+/// Returns the name used for the schema of non-empty arrays of `T`.
+pub(crate) fn nonempty_array_schema_name<T>() -> Cow<'static, str>
+where
+    T: JsonSchema,
+{
+    format!("NonEmptyArray_of_{}", T::schema_name()).into()
+}
+
+/// Returns the id used for the schema of non-empty arrays of `T`.
+pub(crate) fn nonempty_array_schema_id<T>() -> Cow<'static, str>
+where
+    T: JsonSchema,
+{
+    format!("[{}; 1..]", T::schema_id()).into()
+}
+
+#[cfg(feature = "serde")]
+impl<V, const SINGLE_IF_ONE: bool> JsonSchema for crate::serde_ext::OneOrMany<V, SINGLE_IF_ONE>
+where
+    V: crate::serde_ext::Vec1Like + JsonSchema,
+    V::Item: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("OneOrMany_{}", V::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("vec1::serde_ext::OneOrMany<{}>", V::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                generator.subschema_for::<V::Item>(),
+                generator.subschema_for::<V>(),
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use ::schemars::{json_schema, schema_for};
+
+    #[test]
+    fn vec1_schema() {
+        let schema = schema_for!(Vec1<u8>);
+        assert_eq!(schema.get("type").unwrap(), "array");
+        assert_eq!(schema.get("minItems").unwrap(), 1);
+        assert_eq!(schema.get("maxItems"), None);
+        assert_eq!(
+            schema.get("items").unwrap(),
+            &json_schema!({"type": "integer", "format": "uint8", "minimum": 0, "maximum": 255})
+                .to_value()
+        );
+    }
+
+    #[cfg(feature = "smallvec-v1")]
+    #[test]
+    fn smallvec1_schema() {
+        let schema = schema_for!(smallvec_v1::SmallVec1<[bool; 4]>);
+        assert_eq!(schema.get("type").unwrap(), "array");
+        assert_eq!(schema.get("minItems").unwrap(), 1);
+        assert_eq!(
+            schema.get("items").unwrap(),
+            &json_schema!({"type": "boolean"}).to_value()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn one_or_many_schema() {
+        let schema = schema_for!(crate::serde_ext::OneOrMany<Vec1<bool>>);
+        let any_of = schema.get("anyOf").unwrap().as_array().unwrap();
+        assert_eq!(any_of[0], json_schema!({"type": "boolean"}).to_value());
+        assert_eq!(any_of[1]["type"], "array");
+        assert_eq!(any_of[1]["minItems"], 1);
+    }
+}
@@ -43,6 +43,40 @@
 //!            enabling both `serde` and `smallvec-v1` implements `Serialize` and `Deserialize`
 //!            for `SmallVec1` but will *not* enable `smallvec/serde` and as such will not
 //!            implement the `serde` traits for `smallvec::SmallVec`.
+//!            Additionally adds the [`serde_ext`] module with opt-in adapters, e.g. to accept a
+//!            single value in place of a sequence.
+//!
+//! - `schemars`: Implements `schemars::JsonSchema` (v1) for `Vec1` and `SmallVec1` (if `smallvec-v1`
+//!               is enabled), describing a array with `minItems: 1`. If `serde` is enabled, too, it's
+//!               also implemented for [`serde_ext::OneOrMany`], to use it with `#[serde(with = "vec1::serde_ext::one_or_many")]`
+//!               fields add `#[schemars(with = "vec1::serde_ext::OneOrMany<Vec1<T>>")]`.
+//!
+//! - `arbitrary`: Implements `arbitrary::Arbitrary` for `Vec1` and `SmallVec1` (if `smallvec-v1` is enabled),
+//!                always generating at least one element. The `arbitrary` crate requires `std`.
+//!
+//! - `proptest`: Adds the [`proptest_support`] module with strategies for `Vec1` and `SmallVec1` (if `smallvec-v1`
+//!               is enabled) and implements `proptest::arbitrary::Arbitrary` for them. Requires `std`.
+//!
+//! - `quickcheck`: Implements `quickcheck::Arbitrary` (v1) for `Vec1` and `SmallVec1` (if `smallvec-v1`
+//!                 is enabled). Generates between 1 and `Gen::size()` elements and never shrinks to an
+//!                 empty vector. Requires `std`.
+//!
+//! - `borsh`: Implements `borsh::{BorshSerialize, BorshDeserialize}` (v1) for `Vec1` and `SmallVec1` (if
+//!            `smallvec-v1` is enabled), using the same encoding as `Vec`. Deserialization rejects
+//!            a length of zero and doesn't trust the length prefix for pre-allocation.
+//!
+//! - `bincode`: Implements `bincode::{Encode, Decode, BorrowDecode}` (v2) for `Vec1` and `SmallVec1` (if
+//!              `smallvec-v1` is enabled), with the same guarantees as for `borsh`. Note that bincode v2
+//!              requires a newer rustc (1.85) than this crate.
+//!
+//! - `rkyv`: Implements `rkyv::{Archive, Serialize}` (v0.8) for `Vec1`, archiving it as [`rkyv_support::ArchivedVec1`]
+//!           whose validation rejects empty archives. Note that rkyv v0.8 requires a newer rustc (1.81)
+//!           than this crate.
+//!
+//! - `zeroize`: Implements `zeroize::Zeroize` for `Vec1` and `SmallVec1` (if `smallvec-v1` is enabled), including
+//!              their spare capacity, and adds `*_and_zeroize` variants of methods freeing memory (e.g.
+//!              [`Vec1::truncate_and_zeroize()`]). Also adds the [`zeroize_support`] module with a vector which is
+//!              zeroized on drop.
 //!
 //! - `smallvec-v1` : Adds support for a vec1 variation backed by the smallvec crate
 //!                   version 1.x.y. (In the future there will likely be a additional `smallvec-v2`.).
@@ -117,12 +151,32 @@ mod shared;
 #[cfg(feature = "smallvec-v1")]
 pub mod smallvec_v1;
 
+#[cfg(feature = "serde")]
+pub mod serde_ext;
+
+#[cfg(feature = "schemars")]
+mod json_schema;
+
+#[cfg(feature = "proptest")]
+pub mod proptest_support;
+
+#[cfg(feature = "rkyv")]
+pub mod rkyv_support;
+
+#[cfg(feature = "zeroize")]
+pub mod zeroize_support;
+
+mod slice1;
+
+pub use crate::slice1::Slice1;
+
 use core::{
     fmt,
-    iter::{DoubleEndedIterator, ExactSizeIterator, Extend, IntoIterator, Peekable},
+    iter::{DoubleEndedIterator, ExactSizeIterator, Extend, FusedIterator, IntoIterator, Peekable},
     mem::MaybeUninit,
     ops::RangeBounds,
     result::Result as StdResult,
+    slice,
 };
 
 use alloc::{
@@ -151,34 +205,206 @@ use alloc::vec::Drain;
 #[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
 pub struct Size0Error;
 
+impl Size0Error {
+    /// The message used by `Display`, also used for errors of formats which only accept a `&str`.
+    pub(crate) const MESSAGE: &'static str = "Cannot produce a Vec1 with a length of zero.";
+}
+
 impl fmt::Display for Size0Error {
     fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
-        fter.write_str("Cannot produce a Vec1 with a length of zero.")
+        fter.write_str(Self::MESSAGE)
     }
 }
 
 #[cfg(any(feature = "std", test))]
 impl Error for Size0Error {}
 
+// rkyv requires `core::error::Error` (and with it a rustc newer than our minimal supported version).
+#[cfg(all(feature = "rkyv", not(any(feature = "std", test))))]
+impl core::error::Error for Size0Error {}
+
+/// The kind of operation which failed with a [`Size0ErrorWith`].
+#[non_exhaustive]
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub enum Operation {
+    /// `pop`
+    Pop,
+    /// `remove`
+    Remove,
+    /// `swap_remove`
+    SwapRemove,
+    /// `truncate`
+    Truncate,
+    /// `split_at_nonempty`
+    SplitAt,
+    /// A conversion from a (possibly) empty collection.
+    Conversion,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fter.write_str(match self {
+            Operation::Pop => "pop",
+            Operation::Remove => "remove",
+            Operation::SwapRemove => "swap_remove",
+            Operation::Truncate => "truncate",
+            Operation::SplitAt => "split_at_nonempty",
+            Operation::Conversion => "conversion",
+        })
+    }
+}
+
+/// A [`Size0Error`] which knows the failed [`Operation`] and can carry the rejected input.
+///
+/// This is returned by [`Vec1::try_from_returning()`] to hand back the
+/// input of a owning conversion and by the `*_with` variants of shrinking
+/// operations, e.g. [`Vec1::pop_with()`]. It can always be turned into a
+/// plain `Size0Error` using `From`/`?`.
+///
+/// Like for `Size0Error`, `std::error::Error` (which is `core::error::Error` on
+/// rust 1.81+) is implemented if the `std` feature is enabled. Without `std`
+/// `core::error::Error` is only implemented if the `rkyv` feature is enabled, as
+/// it's not available with the minimal rust version of this crate.
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct Size0ErrorWith<I = ()> {
+    operation: Operation,
+    input: I,
+}
+
+impl<I> Size0ErrorWith<I> {
+    /// Creates a new error for given operation and rejected input.
+    pub fn new(operation: Operation, input: I) -> Self {
+        Size0ErrorWith { operation, input }
+    }
+
+    /// Returns the operation which failed.
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Returns a reference to the rejected input.
+    pub fn input(&self) -> &I {
+        &self.input
+    }
+
+    /// Returns the rejected input.
+    pub fn into_input(self) -> I {
+        self.input
+    }
+}
+
+impl<I> fmt::Display for Size0ErrorWith<I> {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fter,
+            "Cannot produce a Vec1 with a length of zero (operation: {}).",
+            self.operation
+        )
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl<I> Error for Size0ErrorWith<I> where I: fmt::Debug {}
+
+#[cfg(all(feature = "rkyv", not(any(feature = "std", test))))]
+impl<I> core::error::Error for Size0ErrorWith<I> where I: fmt::Debug {}
+
+impl<I> From<Size0ErrorWith<I>> for Size0Error {
+    fn from(_: Size0ErrorWith<I>) -> Self {
+        Size0Error
+    }
+}
+
 /// A macro similar to `vec!` to create a `Vec1`.
 ///
 /// If it is called with less then 1 element a
 /// compiler error is triggered (using `compile_error`
 /// to make sure you know what went wrong).
+///
+/// Like `vec!` it also supports the `vec1![elem; n]` syntax, which
+/// clones `elem` to create `n` elements. If `n` is a literal `0` a
+/// compiler error is triggered, if `n` is `0` at runtime this panics.
+///
+/// # Example
+///
+/// ```
+/// # use vec1::vec1;
+/// let v = vec1![1u8; 3];
+/// assert_eq!(v, [1, 1, 1]);
+/// ```
+///
+/// ```compile_fail
+/// # use vec1::vec1;
+/// let v = vec1![1u8; 0];
+/// ```
+///
+/// The list form delegates to `vec!`, so the elements are placed into a
+/// single allocation of exactly the needed size. If more capacity is needed it can be
+/// given with `with_capacity = c;`, which is treated as a lower bound.
+///
+/// ```
+/// # use vec1::vec1;
+/// let v = vec1![with_capacity = 10; 1u8, 2, 3];
+/// assert_eq!(v, [1, 2, 3]);
+/// assert_eq!(v.capacity(), 10);
+/// ```
 #[macro_export]
 macro_rules! vec1 {
     () => (
         compile_error!("Vec1 needs at least 1 element")
     );
+    (with_capacity = $cap:expr; $(,)?) => (
+        compile_error!("Vec1 needs at least 1 element")
+    );
+    (with_capacity = $cap:expr; $first:expr $(, $item:expr)* $(,)?) => (
+        $crate::__private::from_array_with_capacity($cap, [$first $(, $item)*])
+    );
+    ($elem:expr; 0) => (
+        compile_error!("Vec1 needs at least 1 element")
+    );
+    ($elem:expr; $n:expr) => (
+        $crate::Vec1::from_elem($elem, $crate::__private::nonzero_len($n))
+    );
     ($first:expr $(, $item:expr)* , ) => (
         $crate::vec1!($first $(, $item)*)
     );
-    ($first:expr $(, $item:expr)* ) => ({
-        #[allow(unused_mut)]
-        let mut tmp = $crate::Vec1::new($first);
-        $(tmp.push($item);)*
-        tmp
-    });
+    ($first:expr $(, $item:expr)* ) => (
+        $crate::__private::from_vec($crate::__private::vec![$first $(, $item)*])
+    );
+}
+
+#[doc(hidden)]
+pub mod __private {
+    //! Helpers used by the macros of this crate, not part of the public API.
+    use alloc::vec::Vec;
+    use core::num::NonZeroUsize;
+
+    use crate::Vec1;
+
+    pub use alloc::vec;
+
+    /// Wraps a vector created by `vec!` with at least one element.
+    ///
+    /// # Panics
+    ///
+    /// If `vec` is empty, which the `vec1!` macro already rules out.
+    pub fn from_vec<T>(vec: Vec<T>) -> Vec1<T> {
+        assert!(!vec.is_empty(), "Vec1 needs at least 1 element");
+        Vec1(vec)
+    }
+
+    /// Creates a `Vec1` with a capacity of at least `capacity` using a single allocation.
+    pub fn from_array_with_capacity<T, const N: usize>(capacity: usize, array: [T; N]) -> Vec1<T> {
+        assert!(N > 0, "Vec1 needs at least 1 element");
+        let mut vec = Vec::with_capacity(capacity.max(N));
+        vec.extend(array);
+        Vec1(vec)
+    }
+
+    #[track_caller]
+    pub fn nonzero_len(len: usize) -> NonZeroUsize {
+        NonZeroUsize::new(len).expect("Vec1 needs at least 1 element")
+    }
 }
 
 shared_impl! {
@@ -254,6 +480,75 @@ impl<T> Vec1<T> {
         &self.0
     }
 
+    /// Tries to create a `Vec1<T>` from a owned collection, returning it if it's empty.
+    ///
+    /// Unlike the `TryFrom` implementations (which return a [`Size0Error`])
+    /// this hands back the rejected input, e.g. for `VecDeque<T>`, `Box<[T]>`
+    /// or `BinaryHeap<T>`.
+    ///
+    /// # Errors
+    ///
+    /// If the input is empty a [`Size0ErrorWith`] containing the input is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::VecDeque;
+    /// # use vec1::{Operation, Vec1};
+    /// let vec = Vec1::try_from_returning(VecDeque::from([1u8, 2])).unwrap();
+    /// assert_eq!(vec, [1, 2]);
+    ///
+    /// let err = Vec1::<u8>::try_from_returning(VecDeque::new()).unwrap_err();
+    /// assert_eq!(err.operation(), Operation::Conversion);
+    /// let queue: VecDeque<u8> = err.into_input();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn try_from_returning<C>(input: C) -> StdResult<Self, Size0ErrorWith<C>>
+    where
+        C: Into<Vec<T>> + From<Vec<T>>,
+    {
+        let vec = input.into();
+        if vec.is_empty() {
+            Err(Size0ErrorWith::new(Operation::Conversion, C::from(vec)))
+        } else {
+            Ok(Vec1(vec))
+        }
+    }
+
+    /// Creates a `Vec1<T>` from a `Vec<T>` without checking that it's non-empty.
+    ///
+    /// In debug builds this still asserts that `vec` is not empty.
+    ///
+    /// # Safety
+    ///
+    /// `vec` must contain at least one element.
+    ///
+    /// An empty `Vec1` breaks the length >= 1 invariant all its methods are
+    /// written against, e.g. [`Vec1::first()`] and [`Vec1::last()`] panic and
+    /// other methods can return wrong results. It is not undefined behavior
+    /// by itself: no unsafe code in this crate relies on the length being at
+    /// least 1 (leaking e.g. a drain iterator can already leave the vector empty).
+    pub unsafe fn from_vec_unchecked(vec: Vec<T>) -> Self {
+        debug_assert!(!vec.is_empty(), "from_vec_unchecked called with empty vec");
+        Vec1(vec)
+    }
+
+    /// Return a mutable reference to the underlying `Vec`.
+    ///
+    /// Consider [`Vec1::with_vec_mut()`] for a safe alternative.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure the `Vec` contains at least one element
+    /// once the returned reference is no longer used, otherwise the length
+    /// >= 1 invariant of `Vec1` is broken (see [`Vec1::from_vec_unchecked()`]).
+    /// This includes not leaving the `Vec` empty if a panic happens while
+    /// it is mutated.
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<T> {
+        debug_assert!(!self.0.is_empty());
+        &mut self.0
+    }
+
     /// Create a new `Vec1` by consuming `self` and mapping each element.
     ///
     /// This is useful as it keeps the knowledge that the length is >= 1,
@@ -478,6 +773,340 @@ impl<T> Vec1<T> {
             Ok(Splice { vec_splice })
         }
     }
+
+    /// Splits this vector into groups of consecutive elements.
+    ///
+    /// The predicate is called with the last element of the current group
+    /// and the next element, if it returns `true` the next element is added
+    /// to the current group, else a new group is started.
+    ///
+    /// As `self` has at least one element there is at least one group and
+    /// every group has at least one element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let groups = vec1![1, 1, 2, 3, 3, 3].into_chunk_by(|a, b| a == b);
+    /// assert_eq!(groups, vec1![vec1![1, 1], vec1![2], vec1![3, 3, 3]]);
+    /// ```
+    pub fn into_chunk_by<F>(self, mut pred: F) -> Vec1<Vec1<T>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = self.into_iter();
+        //UNWRAP_SAFE: len is at least 1
+        let mut current = Vec1::new(iter.next().unwrap());
+        let mut groups = Vec::new();
+        for element in iter {
+            if pred(current.last(), &element) {
+                current.push(element);
+            } else {
+                groups.push(core::mem::replace(&mut current, Vec1::new(element)));
+            }
+        }
+        Vec1::from_vec_push(groups, current)
+    }
+
+    /// Like [`Vec1::into_chunk_by()`] but returns non-empty sub-slices of `self` instead of taking ownership.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let data = vec1![1, 2, 4, 3, 5];
+    /// let groups = data.chunk_by_ref(|a, b| a < b);
+    /// assert_eq!(groups, vec1![&[1, 2, 4][..], &[3, 5][..]]);
+    /// ```
+    pub fn chunk_by_ref<F>(&self, mut pred: F) -> Vec1<&Slice1<T>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let slice: &[T] = self;
+        let mut groups = Vec::new();
+        let mut start = 0;
+        for idx in 1..slice.len() {
+            if !pred(&slice[idx - 1], &slice[idx]) {
+                groups.push(Slice1::from_non_empty_slice(&slice[start..idx]));
+                start = idx;
+            }
+        }
+        Vec1::from_vec_push(groups, Slice1::from_non_empty_slice(&slice[start..]))
+    }
+
+    /// Like [`Vec1::chunk_by_ref()`] but returns mutable sub-slices.
+    pub fn chunk_by_ref_mut<F>(&mut self, mut pred: F) -> Vec1<&mut Slice1<T>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut split_points = Vec::new();
+        for idx in 1..self.len() {
+            if !pred(&self[idx - 1], &self[idx]) {
+                split_points.push(idx);
+            }
+        }
+        let mut rest: &mut [T] = self;
+        let mut consumed = 0;
+        let mut groups = Vec::with_capacity(split_points.len());
+        for idx in split_points {
+            let (group, tail) = rest.split_at_mut(idx - consumed);
+            groups.push(Slice1::from_non_empty_slice_mut(group));
+            rest = tail;
+            consumed = idx;
+        }
+        Vec1::from_vec_push(groups, Slice1::from_non_empty_slice_mut(rest))
+    }
+
+    /// Groups consecutive elements which map to the same key.
+    ///
+    /// This is like [`Vec1::into_chunk_by()`] but compares the keys returned by
+    /// `key_fn` and returns the key of each group together with the group.
+    ///
+    /// Like with [`Vec1::into_chunk_by()`] only *consecutive* elements are grouped,
+    /// so to group by key the vector normally should be sorted by that key first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let records = vec1![("a", 1), ("a", 2), ("b", 3)];
+    /// let groups = records.group_by_key(|record| record.0);
+    /// assert_eq!(groups, vec1![
+    ///     ("a", vec1![("a", 1), ("a", 2)]),
+    ///     ("b", vec1![("b", 3)]),
+    /// ]);
+    /// ```
+    pub fn group_by_key<K, F>(self, mut key_fn: F) -> Vec1<(K, Vec1<T>)>
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        let mut iter = self.into_iter();
+        //UNWRAP_SAFE: len is at least 1
+        let first = iter.next().unwrap();
+        let mut current = (key_fn(&first), Vec1::new(first));
+        let mut groups = Vec::new();
+        for element in iter {
+            let key = key_fn(&element);
+            if key == current.0 {
+                current.1.push(element);
+            } else {
+                groups.push(core::mem::replace(&mut current, (key, Vec1::new(element))));
+            }
+        }
+        Vec1::from_vec_push(groups, current)
+    }
+
+    /// Like [`Vec1::group_by_key()`] but returns non-empty sub-slices of `self` instead of taking ownership.
+    pub fn group_by_key_ref<K, F>(&self, mut key_fn: F) -> Vec1<(K, &Slice1<T>)>
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        let slice: &[T] = self;
+        let mut groups = Vec::new();
+        let mut start = 0;
+        let mut current_key = key_fn(&slice[0]);
+        for idx in 1..slice.len() {
+            let key = key_fn(&slice[idx]);
+            if key != current_key {
+                let group_key = core::mem::replace(&mut current_key, key);
+                groups.push((group_key, Slice1::from_non_empty_slice(&slice[start..idx])));
+                start = idx;
+            }
+        }
+        let last = Slice1::from_non_empty_slice(&slice[start..]);
+        Vec1::from_vec_push(groups, (current_key, last))
+    }
+
+    /// Partitions the elements into the ones for which `pred` returns `true` (left)
+    /// and the ones for which it returns `false` (right).
+    ///
+    /// Like `Iterator::partition` but as this vector is non-empty at least one
+    /// side is non-empty, which is encoded in the returned [`Partition`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::{vec1, Partition};
+    /// match vec1![1, 2, 3, 4].partition(|v| *v % 2 == 0) {
+    ///     Partition::Both(even, odd) => {
+    ///         assert_eq!(even, [2, 4]);
+    ///         assert_eq!(odd, [1, 3]);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// assert_eq!(vec1![1, 3].partition(|v| *v % 2 == 0), Partition::Right(vec1![1, 3]));
+    /// ```
+    pub fn partition<F>(self, mut pred: F) -> Partition<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.partition_map(|elem| {
+            if pred(&elem) {
+                Either::Left(elem)
+            } else {
+                Either::Right(elem)
+            }
+        })
+    }
+
+    /// Partitions the elements into two vectors of (potentially) different types.
+    ///
+    /// Like [`Vec1::partition()`] but `f` maps each element to either a
+    /// left or a right value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::{vec1, Either, Partition};
+    /// let res = vec1!["1", "x", "3"].partition_map(|v| match v.parse::<u8>() {
+    ///     Ok(num) => Either::Left(num),
+    ///     Err(_) => Either::Right(v),
+    /// });
+    /// assert_eq!(res, Partition::Both(vec1![1, 3], vec1!["x"]));
+    /// ```
+    pub fn partition_map<L, R, F>(self, f: F) -> Partition<L, R>
+    where
+        F: FnMut(T) -> Either<L, R>,
+    {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for either in self.0.into_iter().map(f) {
+            match either {
+                Either::Left(value) => left.push(value),
+                Either::Right(value) => right.push(value),
+            }
+        }
+        match (left.is_empty(), right.is_empty()) {
+            (false, false) => Partition::Both(Vec1(left), Vec1(right)),
+            (false, true) => Partition::Left(Vec1(left)),
+            (true, false) => Partition::Right(Vec1(right)),
+            (true, true) => unreachable!("[BUG] partitioned non-empty vector into two empty ones"),
+        }
+    }
+}
+
+macro_rules! impl_float_stats {
+    ($($float:ty),*) => ($(
+        impl Vec1<$float> {
+            /// Returns the arithmetic mean of all elements.
+            ///
+            /// As the vector is never empty this never divides by zero.
+            pub fn mean(&self) -> $float {
+                self.iter().sum::<$float>() / self.len() as $float
+            }
+
+            /// Returns the median of all elements.
+            ///
+            /// For an even number of elements this is the mean of the two middle
+            /// elements. Elements are ordered using `total_cmp`, i.e. `NaN`s are
+            /// ordered after (or, if negative, before) all other values.
+            pub fn median(&self) -> $float {
+                let mut values = self.0.clone();
+                let mid = values.len() / 2;
+                let (lower, upper, _) = values.select_nth_unstable_by(mid, <$float>::total_cmp);
+                if self.len() % 2 == 0 {
+                    //UNWRAP_SAFE: len is even and > 0, so there is a lower half
+                    let lower_max = *lower.iter().max_by(|a, b| a.total_cmp(b)).unwrap();
+                    // halve first, so that e.g. two `MAX` values don't overflow
+                    lower_max / 2.0 + *upper / 2.0
+                } else {
+                    *upper
+                }
+            }
+        }
+    )*);
+}
+
+impl_float_stats!(f32, f64);
+
+macro_rules! impl_integer_stats {
+    ($($int:ty),*) => ($(
+        impl Vec1<$int> {
+            /// Returns the arithmetic mean of all elements as `f64`.
+            ///
+            /// All elements are converted to `f64` before summing them up, so the
+            /// sum can't overflow. But this conversion is lossy for values with a
+            /// magnitude above `2^53`, so the result might be imprecise for
+            /// huge 64 and 128 bit values. As the vector is never empty this
+            /// never divides by zero.
+            pub fn mean(&self) -> f64 {
+                self.iter().map(|&value| value as f64).sum::<f64>() / self.len() as f64
+            }
+
+            /// Returns the median of all elements as `f64`.
+            ///
+            /// For an even number of elements this is the mean of the two middle
+            /// elements.
+            ///
+            /// The middle elements are selected on the integers, but then converted
+            /// to `f64`, which is lossy for values with a magnitude above `2^53`.
+            pub fn median(&self) -> f64 {
+                let mut values = self.0.clone();
+                let mid = values.len() / 2;
+                let (lower, upper, _) = values.select_nth_unstable(mid);
+                if self.len() % 2 == 0 {
+                    //UNWRAP_SAFE: len is even and > 0, so there is a lower half
+                    let lower_max = *lower.iter().max().unwrap();
+                    (lower_max as f64 + *upper as f64) / 2.0
+                } else {
+                    *upper as f64
+                }
+            }
+        }
+    )*);
+}
+
+impl_integer_stats!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A value which is either of type `L` or `R`, see [`Vec1::partition_map()`].
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum Either<L, R> {
+    /// A left value.
+    Left(L),
+    /// A right value.
+    Right(R),
+}
+
+/// The result of partitioning a non-empty vector, at least one side is non-empty.
+///
+/// See [`Vec1::partition()`] and [`Vec1::partition_map()`].
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+pub enum Partition<L, R = L> {
+    /// Only the left side has elements.
+    Left(Vec1<L>),
+    /// Only the right side has elements.
+    Right(Vec1<R>),
+    /// Both sides have elements.
+    Both(Vec1<L>, Vec1<R>),
+}
+
+impl<L, R> Partition<L, R> {
+    /// Returns the left side if it is non-empty.
+    pub fn left(&self) -> Option<&Vec1<L>> {
+        match self {
+            Partition::Left(left) | Partition::Both(left, _) => Some(left),
+            Partition::Right(_) => None,
+        }
+    }
+
+    /// Returns the right side if it is non-empty.
+    pub fn right(&self) -> Option<&Vec1<R>> {
+        match self {
+            Partition::Right(right) | Partition::Both(_, right) => Some(right),
+            Partition::Left(_) => None,
+        }
+    }
+
+    /// Turns this into a `(left, right)` tuple of (possibly empty) vectors.
+    pub fn into_vecs(self) -> (Vec<L>, Vec<R>) {
+        match self {
+            Partition::Left(left) => (left.0, Vec::new()),
+            Partition::Right(right) => (Vec::new(), right.0),
+            Partition::Both(left, right) => (left.0, right.0),
+        }
+    }
 }
 
 impl_wrapper! {
@@ -554,6 +1183,121 @@ where
     }
 }
 
+macro_rules! slice_iter_wrapper {
+    ($(#[$attr:meta])* pub struct $name:ident<'a, T>($inner:ident $(, $first:ident)?);) => (
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<'a, T> {
+            inner: slice::$inner<'a, T>,
+            $($first: &'a Slice1<T>,)?
+        }
+
+        impl<'a, T> Clone for $name<'a, T> {
+            fn clone(&self) -> Self {
+                $name {
+                    inner: self.inner.clone(),
+                    $($first: self.$first,)?
+                }
+            }
+        }
+
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = &'a Slice1<T>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next().map(Slice1::from_non_empty_slice)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.inner.nth(n).map(Slice1::from_non_empty_slice)
+            }
+
+            #[inline]
+            fn last(self) -> Option<Self::Item> {
+                self.inner.last().map(Slice1::from_non_empty_slice)
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back().map(Slice1::from_non_empty_slice)
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $name<'a, T> {}
+
+        impl<'a, T> FusedIterator for $name<'a, T> {}
+    );
+}
+
+slice_iter_wrapper! {
+    /// Iterator over non-empty chunks, see [`Vec1::chunks1()`].
+    ///
+    /// As the vector it was created from is never empty this
+    /// iterator always yields at least one chunk.
+    pub struct Chunks1<'a, T>(Chunks, first);
+}
+
+impl<'a, T> Chunks1<'a, T> {
+    /// Returns the first chunk of the vector.
+    ///
+    /// This is the chunk the iterator yielded (or yields) first and
+    /// doesn't depend on how far the iterator was advanced.
+    pub fn first_chunk(&self) -> &'a Slice1<T> {
+        self.first
+    }
+}
+
+slice_iter_wrapper! {
+    /// Iterator over non-empty chunks starting at the end, see [`Vec1::rchunks1()`].
+    ///
+    /// As the vector it was created from is never empty this
+    /// iterator always yields at least one chunk.
+    pub struct RChunks1<'a, T>(RChunks, first);
+}
+
+impl<'a, T> RChunks1<'a, T> {
+    /// Returns the first chunk starting at the end, i.e. the last `chunk_size` elements.
+    ///
+    /// This is the chunk the iterator yielded (or yields) first and
+    /// doesn't depend on how far the iterator was advanced.
+    pub fn first_chunk(&self) -> &'a Slice1<T> {
+        self.first
+    }
+}
+
+slice_iter_wrapper! {
+    /// Iterator over non-empty chunks of exactly the chunk size, see [`Vec1::chunks_exact1()`].
+    ///
+    /// If the chunk size is greater than the length of the vector this
+    /// iterator doesn't yield any chunk and [`ChunksExact1::remainder()`]
+    /// returns the whole vector.
+    pub struct ChunksExact1<'a, T>(ChunksExact);
+}
+
+impl<'a, T> ChunksExact1<'a, T> {
+    /// Returns the up to `chunk_size - 1` elements which are not part of any chunk.
+    pub fn remainder(&self) -> &'a [T] {
+        self.inner.remainder()
+    }
+}
+
+slice_iter_wrapper! {
+    /// Iterator over non-empty overlapping windows, see [`Vec1::windows1()`].
+    ///
+    /// If the window size is greater than the length of the vector this
+    /// iterator doesn't yield any window.
+    pub struct Windows1<'a, T>(Windows);
+}
+
 impl<A, B> PartialEq<Vec1<B>> for Vec1<A>
 where
     A: PartialEq<B>,
@@ -792,6 +1536,68 @@ mod test {
         }
     }
 
+    mod Size0ErrorWith {
+        #![allow(non_snake_case)]
+        use super::super::*;
+        use std::{error::Error as StdError, string::ToString};
+
+        #[test]
+        fn implements_std_error() {
+            fn comp_check<T: StdError>() {}
+            comp_check::<Size0ErrorWith>();
+            comp_check::<Size0ErrorWith<VecDeque<u8>>>();
+        }
+
+        #[test]
+        fn returns_input() {
+            let err = Vec1::<u8>::try_from_returning(Box::<[u8]>::default()).unwrap_err();
+            assert_eq!(err.operation(), Operation::Conversion);
+            assert_eq!(
+                err.to_string(),
+                "Cannot produce a Vec1 with a length of zero (operation: conversion)."
+            );
+            assert!(err.input().is_empty());
+            let input: Box<[u8]> = err.into_input();
+            assert!(input.is_empty());
+
+            let vec = Vec1::try_from_returning(BinaryHeap::from(std::vec![3u8])).unwrap();
+            assert_eq!(vec, [3]);
+        }
+
+        #[test]
+        fn converts_to_size0_error() {
+            fn convert() -> Result<Vec1<u8>, Size0Error> {
+                Ok(Vec1::try_from_returning(VecDeque::new())?)
+            }
+            assert_eq!(convert(), Err(Size0Error));
+        }
+
+        #[test]
+        fn records_operation() {
+            let mut a = vec1![1u8, 2];
+            assert_eq!(
+                a.truncate_with(0).unwrap_err().operation(),
+                Operation::Truncate
+            );
+            assert_eq!(a.swap_remove_with(0), Ok(1));
+            assert_eq!(
+                a.swap_remove_with(0).unwrap_err().operation(),
+                Operation::SwapRemove
+            );
+            assert_eq!(a.remove_with(0).unwrap_err().operation(), Operation::Remove);
+            let err = a.pop_with().unwrap_err();
+            assert_eq!(err.operation(), Operation::Pop);
+            assert_eq!(
+                err.to_string(),
+                "Cannot produce a Vec1 with a length of zero (operation: pop)."
+            );
+            a.push(3);
+            assert_eq!(a.pop_with(), Ok(3));
+            assert_eq!(a.truncate_with(1), Ok(()));
+            assert_eq!(a, [2u8]);
+        }
+    }
+
     mod Vec1 {
         use core::num::NonZeroUsize;
         use proptest::prelude::*;
@@ -818,6 +1624,75 @@ mod test {
             //TODO comptest vec1![] => compiler error
         }
 
+        #[test]
+        fn vec1_macro_allocates_exactly_once() {
+            let a = vec1![1u64, 2, 3, 4, 5, 6, 7, 8, 9];
+            assert_eq!(a.capacity(), 9);
+            assert_eq!(a, &[1u64, 2, 3, 4, 5, 6, 7, 8, 9]);
+        }
+
+        #[test]
+        fn with_capacity_vec1_macro() {
+            let a = vec1![with_capacity = 16; 1u8, 2, 3];
+            assert_eq!(a, &[1u8, 2, 3]);
+            assert_eq!(a.capacity(), 16);
+
+            let a = vec1![with_capacity = 1; 1u8, 2, 3,];
+            assert_eq!(a, &[1u8, 2, 3]);
+            assert_eq!(a.capacity(), 3);
+        }
+
+        #[test]
+        fn repeat_vec1_macro() {
+            let a = vec1![3u8; 4];
+            assert_eq!(a, &[3, 3, 3, 3]);
+
+            let len = 2;
+            let a = vec1!["a"; len];
+            assert_eq!(a, &["a", "a"]);
+
+            catch_unwind(|| {
+                let len = 0;
+                let _ = vec1![1u8; len];
+            })
+            .unwrap_err();
+        }
+
+        #[test]
+        fn from_elem() {
+            let a = Vec1::from_elem(7u8, NonZeroUsize::new(3).unwrap());
+            assert_eq!(a, &[7u8, 7, 7]);
+            assert_eq!(a.capacity(), 3);
+        }
+
+        #[test]
+        fn from_fn() {
+            let a = Vec1::from_fn(NonZeroUsize::new(4).unwrap(), |idx| idx * 10);
+            assert_eq!(a, &[0usize, 10, 20, 30]);
+        }
+
+        #[test]
+        fn try_from_fn() {
+            let len = NonZeroUsize::new(3).unwrap();
+            let a = Vec1::try_from_fn(len, |idx| Ok::<_, ()>(idx as u8));
+            assert_eq!(a, Ok(vec1![0u8, 1, 2]));
+
+            let a = Vec1::<u8>::try_from_fn(len, |idx| if idx == 1 { Err(idx) } else { Ok(0) });
+            assert_eq!(a, Err(1));
+        }
+
+        #[test]
+        fn repeat1() {
+            let a = vec1![1u8, 2];
+            assert_eq!(
+                a.repeat1(NonZeroUsize::new(3).unwrap()),
+                &[1u8, 2, 1, 2, 1, 2]
+            );
+            assert_eq!(a.repeat1(NonZeroUsize::new(1).unwrap()), a);
+            // `<[T]>::repeat` is not shadowed
+            assert_eq!(a.repeat(2), std::vec![1u8, 2, 1, 2]);
+        }
+
         #[test]
         fn new() {
             let a = Vec1::new(1u8);
@@ -892,6 +1767,26 @@ mod test {
             a.try_truncate(0).unwrap_err();
         }
 
+        #[test]
+        fn from_vec_unchecked() {
+            let a = unsafe { Vec1::from_vec_unchecked(std::vec![1u8, 2]) };
+            assert_eq!(a, vec1![1u8, 2]);
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic]
+        fn from_vec_unchecked_asserts_in_debug() {
+            let _ = unsafe { Vec1::<u8>::from_vec_unchecked(Vec::new()) };
+        }
+
+        #[test]
+        fn as_mut_vec() {
+            let mut a = vec1![1u8, 2];
+            unsafe { a.as_mut_vec() }.push(3);
+            assert_eq!(a, vec1![1u8, 2, 3]);
+        }
+
         #[test]
         fn as_slice() {
             let a = vec1![22u8, 12, 9];
@@ -1011,62 +1906,388 @@ mod test {
             assert_eq!(a.last(), &8);
         }
 
-        proptest! {
-            #[test]
-            fn same_behavior_as_vec_except_when_empty(
-                data in (1usize..=20)
-                    .prop_flat_map(|size| prop::collection::vec(any::<bool>(), size..=size))
-            ) {
-                use std::convert::TryFrom;
-
-                let mut vec = data.clone();
-                let mut vec1 = Vec1::try_from(data).unwrap();
-                let last = *vec1.last();
+        mod retain_mut {
+            use super::*;
+            use core::cell::Cell;
+            use std::panic::{catch_unwind, AssertUnwindSafe};
+
+            struct Tracked<'a> {
+                value: u8,
+                drops: &'a Cell<usize>,
+                panic_on_drop: bool,
+            }
+
+            impl Drop for Tracked<'_> {
+                fn drop(&mut self) {
+                    self.drops.set(self.drops.get() + 1);
+                    if self.panic_on_drop {
+                        panic!("drop panic");
+                    }
+                }
+            }
+
+            fn tracked<'a>(values: &[u8], drops: &'a Cell<usize>) -> Vec1<Tracked<'a>> {
+                Vec1::try_from_vec(
+                    values
+                        .iter()
+                        .map(|&value| Tracked {
+                            value,
+                            drops,
+                            panic_on_drop: false,
+                        })
+                        .collect(),
+                )
+                .unwrap()
+            }
+
+            fn values(vec: &Vec1<Tracked<'_>>) -> Vec<u8> {
+                vec.iter().map(|t| t.value).collect()
+            }
+
+            #[test]
+            fn drops_elements_during_the_pass() {
+                let drops = Cell::new(0);
+                let mut vec = tracked(&[1, 2, 3, 4], &drops);
+                let mut seen_drops = Vec::new();
+                vec.retain_mut(|t| {
+                    seen_drops.push(drops.get());
+                    t.value % 2 == 0
+                })
+                .unwrap();
+                assert_eq!(seen_drops, [0, 1, 1, 2]);
+                assert_eq!(values(&vec), [2, 4]);
+                drop(vec);
+                assert_eq!(drops.get(), 4);
+            }
+
+            #[test]
+            fn panic_in_predicate_keeps_unprocessed_elements() {
+                let drops = Cell::new(0);
+                let mut vec = tracked(&[1, 2, 3, 4, 5], &drops);
+                catch_unwind(AssertUnwindSafe(|| {
+                    let _ = vec.retain_mut(|t| {
+                        if t.value == 4 {
+                            panic!("predicate panic");
+                        }
+                        t.value == 2
+                    });
+                }))
+                .unwrap_err();
+                assert_eq!(values(&vec), [2, 4, 5]);
+                assert_eq!(drops.get(), 2);
+                drop(vec);
+                assert_eq!(drops.get(), 5);
+            }
+
+            #[test]
+            fn panic_on_last_element_keeps_it() {
+                let drops = Cell::new(0);
+                let mut vec = tracked(&[1, 2, 3], &drops);
+                catch_unwind(AssertUnwindSafe(|| {
+                    let _ = vec.retain_mut(|t| {
+                        if t.value == 3 {
+                            panic!("predicate panic");
+                        }
+                        false
+                    });
+                }))
+                .unwrap_err();
+                assert_eq!(values(&vec), [3]);
+                drop(vec);
+                assert_eq!(drops.get(), 3);
+            }
+
+            #[test]
+            fn panic_in_drop_of_removed_element() {
+                let drops = Cell::new(0);
+                let mut vec = tracked(&[1, 2, 3, 4], &drops);
+                vec[1].panic_on_drop = true;
+                catch_unwind(AssertUnwindSafe(|| {
+                    let _ = vec.retain_mut(|t| t.value == 1);
+                }))
+                .unwrap_err();
+                assert_eq!(values(&vec), [1, 3, 4]);
+                assert_eq!(drops.get(), 1);
+                drop(vec);
+                assert_eq!(drops.get(), 4);
+            }
+
+            #[test]
+            fn keeps_last_if_all_are_rejected() {
+                let drops = Cell::new(0);
+                let mut vec = tracked(&[1, 2, 3], &drops);
+                let mut calls = 0;
+                let res = vec.retain_mut(|t| {
+                    calls += 1;
+                    t.value += 10;
+                    false
+                });
+                assert_eq!(res, Err(Size0Error));
+                assert_eq!(calls, 3);
+                assert_eq!(values(&vec), [13]);
+                assert_eq!(drops.get(), 2);
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn same_behavior_as_vec_except_when_empty(
+                data in (1usize..=20)
+                    .prop_flat_map(|size| prop::collection::vec(any::<bool>(), size..=size))
+            ) {
+                use std::convert::TryFrom;
+
+                let mut vec = data.clone();
+                let mut vec1 = Vec1::try_from(data).unwrap();
+                let last = *vec1.last();
+
+                vec.retain(|value| *value == true);
+                let res = vec1.retain(|value| *value == true);
+
+                if vec.is_empty() {
+                    assert_eq!(res, Err(Size0Error));
+                    assert_eq!(vec1.len(), 1);
+                    assert_eq!(vec1.last(), &last);
+                } else {
+                    assert_eq!(res, Ok(()));
+                    assert_eq!(&*vec, &*vec1);
+                }
+
+            }
+        }
+
+        #[test]
+        fn dedup_by_key() {
+            let mut a = vec1![0xA3u16, 0x10F, 0x20F];
+            a.dedup_by_key(|f| *f & 0xFF);
+            assert_eq!(a, &[0xA3, 0x10F]);
+        }
+
+        #[test]
+        fn dedup_by() {
+            let mut a = vec1![1u8, 7u8, 12u8, 10u8];
+            a.dedup_by(|l, r| (*l % 2 == 0) == (*r % 2 == 0));
+            assert_eq!(a, &[1u8, 12u8]);
+        }
+
+        #[test]
+        fn push() {
+            let mut a = vec1![1u8, 2, 10];
+            a.push(1);
+            assert_eq!(a, &[1u8, 2, 10, 1]);
+        }
+
+        #[test]
+        fn pop() {
+            let mut a = vec1![3u8, 10, 2];
+            a.pop().unwrap();
+            assert_eq!(a, &[3u8, 10]);
+            a.pop().unwrap();
+            assert_eq!(a, &[3u8]);
+            a.pop().unwrap_err();
+        }
+
+        #[test]
+        fn retain_or_keep_last() {
+            let mut a = vec1![1u8, 2, 3, 4, 5];
+            assert_eq!(a.retain_or_keep_last(|v| *v % 2 == 1), std::vec![2u8, 4]);
+            assert_eq!(a, [1u8, 3, 5]);
+            assert_eq!(a.retain_or_keep_last(|_| false), std::vec![1u8, 3]);
+            assert_eq!(a, [5u8]);
+        }
+
+        #[test]
+        fn retain_or_keep_last_panic_keeps_unvisited_elements() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                a.retain_or_keep_last(|v| if *v == 3 { panic!("fail") } else { *v == 2 })
+            }));
+            assert!(res.is_err());
+            assert_eq!(a, [2u8, 3, 4]);
+        }
+
+        #[test]
+        fn retain_or_keep_first() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            assert_eq!(a.retain_or_keep_first(|v| *v > 2), std::vec![1u8, 2]);
+            assert_eq!(a, [3u8, 4]);
+            assert_eq!(a.retain_or_keep_first(|_| false), std::vec![4u8]);
+            assert_eq!(a, [3u8]);
+
+            let mut a = vec1![1u8, 2, 3, 4, 5];
+            assert_eq!(a.retain_or_keep_first(|v| *v == 4), std::vec![1u8, 2, 3, 5]);
+            assert_eq!(a, [4u8]);
+        }
+
+        #[test]
+        fn retain_or_keep_first_panic_keeps_unvisited_elements() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                a.retain_or_keep_first(|v| if *v == 3 { panic!("fail") } else { false })
+            }));
+            assert!(res.is_err());
+            assert_eq!(a, [1u8, 3, 4]);
+        }
+
+        #[test]
+        fn retain_or_keep_keeps_capacity() {
+            let mut a = Vec1::with_capacity(0u8, 16);
+            a.extend(1..10);
+            let capacity = a.capacity();
+            let ptr = a.as_ptr();
+            assert_eq!(a.retain_or_keep_last(|v| *v % 3 == 0).len(), 6);
+            assert_eq!(a, [0u8, 3, 6, 9]);
+            assert_eq!(a.retain_or_keep_first(|_| false), std::vec![3u8, 6, 9]);
+            assert_eq!(a, [0u8]);
+            assert_eq!(a.capacity(), capacity);
+            assert_eq!(a.as_ptr(), ptr);
+        }
+
+        #[test]
+        fn drain_keep_one() {
+            let mut a = vec1![1u8, 2, 3, 4, 5];
+            assert_eq!(a.drain_keep_one(3..), std::vec![4u8, 5]);
+            assert_eq!(a.drain_keep_one(0..0), Vec::<u8>::new());
+            assert_eq!(a.drain_keep_one(0..3), std::vec![2u8, 3]);
+            assert_eq!(a, [1u8]);
+            assert_eq!(a.drain_keep_one(..), Vec::<u8>::new());
+            assert_eq!(a, [1u8]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn drain_keep_one_panics_if_out_of_bounds() {
+            let mut a = vec1![1u8, 2];
+            a.drain_keep_one(..3);
+        }
+
+        #[test]
+        fn truncate_saturating() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            assert_eq!(a.truncate_saturating(10), Vec::<u8>::new());
+            assert_eq!(a.truncate_saturating(3), std::vec![4u8]);
+            assert_eq!(a.truncate_saturating(0), std::vec![2u8, 3]);
+            assert_eq!(a, [1u8]);
+        }
+
+        #[test]
+        fn extract_if() {
+            let mut a = vec1![1u8, 2, 3, 4, 5, 6];
+            let mut iter = a.extract_if(1..5, |v| *v % 2 == 0);
+            assert_eq!(iter.next(), Some(2));
+            assert!(!iter.kept_element());
+            drop(iter);
+            assert_eq!(a, [1u8, 3, 4, 5, 6]);
+
+            let extracted = a.extract_if(.., |v| *v > 3).collect::<Vec<_>>();
+            assert_eq!(extracted, [4u8, 5, 6]);
+            assert_eq!(a, [1u8, 3]);
+
+            let mut iter = a.extract_if(.., |v| {
+                *v += 1;
+                true
+            });
+            assert_eq!(iter.next(), Some(2));
+            assert_eq!(iter.next(), None);
+            assert!(iter.kept_element());
+            drop(iter);
+            assert_eq!(a, [4u8]);
+        }
+
+        #[test]
+        fn extract_if_panic_in_predicate() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                a.extract_if(.., |v| if *v == 3 { panic!("fail") } else { true })
+                    .for_each(drop);
+            }));
+            assert!(res.is_err());
+            assert_eq!(a, [3u8, 4]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn extract_if_panics_if_out_of_bounds() {
+            let mut a = vec1![1u8, 2];
+            a.extract_if(..3, |_| true);
+        }
+
+        /// Creates a vector with `len == capacity`.
+        fn full_vec1(len: u8) -> Vec1<u8> {
+            let mut a = Vec1::with_capacity(0u8, len.into());
+            a.extend(1..len);
+            assert_eq!(a.len(), a.capacity());
+            a
+        }
 
-                vec.retain(|value| *value == true);
-                let res = vec1.retain(|value| *value == true);
+        #[test]
+        fn clear_to() {
+            let mut a = Vec1::with_capacity(1u8, 10);
+            a.extend([2, 3]);
+            let old = a.clear_to(4).collect::<Vec<_>>();
+            assert_eq!(old, [1u8, 2, 3]);
+            assert_eq!(a, [4u8]);
+            assert_eq!(a.capacity(), 10);
 
-                if vec.is_empty() {
-                    assert_eq!(res, Err(Size0Error));
-                    assert_eq!(vec1.len(), 1);
-                    assert_eq!(vec1.last(), &last);
-                } else {
-                    assert_eq!(res, Ok(()));
-                    assert_eq!(&*vec, &*vec1);
-                }
+            let mut a = full_vec1(3);
+            let ptr = a.as_ptr();
+            assert_eq!(a.clear_to(7).collect::<Vec<_>>(), [0u8, 1, 2]);
+            assert_eq!(a, [7u8]);
+            assert_eq!((a.as_ptr(), a.capacity()), (ptr, 3));
+        }
 
-            }
+        #[test]
+        fn clear_to_leaked() {
+            let mut a = full_vec1(3);
+            core::mem::forget(a.clear_to(7));
+            assert_eq!(a, [7u8]);
         }
 
         #[test]
-        fn dedup_by_key() {
-            let mut a = vec1![0xA3u16, 0x10F, 0x20F];
-            a.dedup_by_key(|f| *f & 0xFF);
-            assert_eq!(a, &[0xA3, 0x10F]);
+        fn replace_all() {
+            let mut a = Vec1::with_capacity(1u8, 10);
+            a.replace_all(vec1![2u8, 3]);
+            assert_eq!(a, [2u8, 3]);
+            assert_eq!(a.capacity(), 10);
+
+            let mut a = full_vec1(3);
+            let ptr = a.as_ptr();
+            a.replace_all(vec1![4u8, 5, 6]);
+            assert_eq!(a, [4u8, 5, 6]);
+            assert_eq!((a.as_ptr(), a.capacity()), (ptr, 3));
         }
 
         #[test]
-        fn dedup_by() {
-            let mut a = vec1![1u8, 7u8, 12u8, 10u8];
-            a.dedup_by(|l, r| (*l % 2 == 0) == (*r % 2 == 0));
-            assert_eq!(a, &[1u8, 12u8]);
+        fn replace_with_iter() {
+            let mut a = Vec1::with_capacity(1u8, 10);
+            a.push(2);
+            a.replace_with_iter(5, [6, 7]);
+            assert_eq!(a, [5u8, 6, 7]);
+            assert_eq!(a.capacity(), 10);
+
+            let mut a = full_vec1(3);
+            let ptr = a.as_ptr();
+            a.replace_with_iter(5, [6]);
+            assert_eq!(a, [5u8, 6]);
+            assert_eq!((a.as_ptr(), a.capacity()), (ptr, 3));
         }
 
         #[test]
-        fn push() {
-            let mut a = vec1![1u8, 2, 10];
-            a.push(1);
-            assert_eq!(a, &[1u8, 2, 10, 1]);
+        fn replace_with_iter_panic_keeps_new_elements() {
+            let mut a = vec1![1u8, 2];
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                a.replace_with_iter(5, (6..8).map(|v| if v == 7 { panic!("fail") } else { v }));
+            }));
+            assert!(res.is_err());
+            assert_eq!(a, [5u8, 6]);
         }
 
         #[test]
-        fn pop() {
-            let mut a = vec1![3u8, 10, 2];
-            a.pop().unwrap();
-            assert_eq!(a, &[3u8, 10]);
-            a.pop().unwrap();
-            assert_eq!(a, &[3u8]);
-            a.pop().unwrap_err();
+        fn pop_all_but_first() {
+            let mut a = vec1![1u8, 2, 3];
+            assert_eq!(a.pop_all_but_first(), std::vec![2u8, 3]);
+            assert_eq!(a.pop_all_but_first(), Vec::<u8>::new());
+            assert_eq!(a, [1u8]);
         }
 
         #[test]
@@ -1363,6 +2584,141 @@ mod test {
             assert_eq!(first, 12);
         }
 
+        #[test]
+        fn partition() {
+            let res = vec1![1u8, 2, 3, 4].partition(|v| *v > 2);
+            assert_eq!(res, Partition::Both(vec1![3u8, 4], vec1![1u8, 2]));
+            assert_eq!(res.left(), Some(&vec1![3u8, 4]));
+            assert_eq!(res.right(), Some(&vec1![1u8, 2]));
+
+            let res = vec1![1u8, 2].partition(|_| true);
+            assert_eq!(res, Partition::Left(vec1![1u8, 2]));
+            assert_eq!(res.right(), None);
+            assert_eq!(res.into_vecs(), (std::vec![1u8, 2], Vec::new()));
+
+            let res = vec1![1u8, 2].partition(|_| false);
+            assert_eq!(res, Partition::Right(vec1![1u8, 2]));
+            assert_eq!(res.left(), None);
+        }
+
+        #[test]
+        fn partition_map() {
+            let res = vec1![1u8, 2, 3].partition_map(|v| {
+                if v % 2 == 0 {
+                    Either::Left(std::string::ToString::to_string(&v))
+                } else {
+                    Either::Right(v)
+                }
+            });
+            assert_eq!(
+                res,
+                Partition::Both(vec1![std::string::String::from("2")], vec1![1u8, 3])
+            );
+            let (left, right) = res.into_vecs();
+            assert_eq!(left, ["2"]);
+            assert_eq!(right, [1u8, 3]);
+        }
+
+        #[test]
+        fn sum1() {
+            assert_eq!(vec1![1u8, 2, 3].sum1(), 6);
+            assert_eq!(
+                vec1![core::num::Wrapping(200u8), core::num::Wrapping(100)].sum1(),
+                core::num::Wrapping(44)
+            );
+        }
+
+        #[test]
+        fn product1() {
+            assert_eq!(vec1![2u8, 3, 4].product1(), 24);
+            assert_eq!(vec1![1.5f32].product1(), 1.5);
+        }
+
+        #[test]
+        fn fold1() {
+            assert_eq!(vec1![1u8, 5, 3].fold1(core::cmp::max), 5);
+            assert_eq!(vec1![1u8, 5, 3].fold1_ref(core::cmp::min), &1);
+            assert_eq!(vec1![1u8, 5, 3].fold1_mut(core::cmp::max), &mut 5);
+        }
+
+        #[test]
+        fn mean() {
+            assert_eq!(vec1![1.0f64, 2.0, 6.0].mean(), 3.0);
+            assert_eq!(vec1![1.5f32].mean(), 1.5);
+            assert_eq!(vec1![1u8, 2].mean(), 1.5);
+            assert_eq!(vec1![255u8, 255].mean(), 255.0);
+            assert_eq!(vec1![-3i64, 3, 6].mean(), 2.0);
+        }
+
+        #[test]
+        fn median() {
+            assert_eq!(vec1![3.0f64, 1.0, 2.0].median(), 2.0);
+            assert_eq!(vec1![4.0f32, 1.0, 3.0, 2.0].median(), 2.5);
+            assert_eq!(vec1![7.0f64].median(), 7.0);
+            assert_eq!(vec1![5u32, 1, 3].median(), 3.0);
+            assert_eq!(vec1![5u32, 1, 4, 2].median(), 3.0);
+            assert_eq!(vec1![-1i8, 0].median(), -0.5);
+            assert_eq!(vec1![f64::MAX, f64::MAX].median(), f64::MAX);
+            assert_eq!(vec1![f32::MIN, f32::MIN].median(), f32::MIN);
+            assert_eq!(vec1![u64::MAX, u64::MAX].median(), u64::MAX as f64);
+        }
+
+        #[test]
+        fn split_into_first_and_vec1() {
+            let (first, rest) = vec1![1u8, 2, 3].split_into_first_and_vec1();
+            assert_eq!(first, 1);
+            assert_eq!(rest, Some(vec1![2u8, 3]));
+            let (first, rest) = vec1![1u8].split_into_first_and_vec1();
+            assert_eq!(first, 1);
+            assert_eq!(rest, None);
+        }
+
+        #[test]
+        fn split_at_nonempty() {
+            let (left, right) = vec1![1u8, 2, 3].split_at_nonempty(2).unwrap();
+            assert_eq!(left, [1u8, 2]);
+            assert_eq!(right, [3u8]);
+            let err = vec1![1u8, 2].split_at_nonempty(0).unwrap_err();
+            assert_eq!(err.operation(), Operation::SplitAt);
+            assert_eq!(err.into_input(), [1u8, 2]);
+            let err = vec1![1u8, 2].split_at_nonempty(2).unwrap_err();
+            assert_eq!(err.into_input(), [1u8, 2]);
+            let err = vec1![1u8].split_at_nonempty(1).unwrap_err();
+            assert_eq!(err.into_input(), [1u8]);
+        }
+
+        #[test]
+        fn split_first_ref() {
+            let a = vec1![1u8, 2, 3];
+            assert_eq!(a.split_first_ref(), (&1u8, &[2u8, 3][..]));
+            let a = vec1![1u8];
+            assert_eq!(a.split_first_ref(), (&1u8, &[][..]));
+        }
+
+        #[test]
+        fn split_first_ref_mut() {
+            let mut a = vec1![1u8, 2, 3];
+            let (first, rest) = a.split_first_ref_mut();
+            *first = 10;
+            rest[0] = 20;
+            assert_eq!(a, [10u8, 20, 3]);
+        }
+
+        #[test]
+        fn split_last_ref() {
+            let a = vec1![1u8, 2, 3];
+            assert_eq!(a.split_last_ref(), (&3u8, &[1u8, 2][..]));
+        }
+
+        #[test]
+        fn split_last_ref_mut() {
+            let mut a = vec1![1u8, 2, 3];
+            let (last, rest) = a.split_last_ref_mut();
+            *last = 30;
+            rest[0] = 10;
+            assert_eq!(a, [10u8, 2, 30]);
+        }
+
         #[test]
         fn from_vec_push() {
             assert_eq!(Vec1::from_vec_push(std::vec![], 1u8), vec1![1]);
@@ -1444,6 +2800,236 @@ mod test {
             assert_eq!(a, ["a", "b", "c", "d", "b", "c"]);
         }
 
+        #[test]
+        fn into_chunk_by() {
+            let a = vec1![1u8, 1, 2, 3, 3, 1];
+            let groups = a.into_chunk_by(|l, r| l == r);
+            assert_eq!(
+                groups,
+                vec1![vec1![1u8, 1], vec1![2], vec1![3, 3], vec1![1]]
+            );
+
+            let groups = vec1![7u8].into_chunk_by(|_, _| false);
+            assert_eq!(groups, vec1![vec1![7u8]]);
+        }
+
+        #[test]
+        fn chunk_by_ref() {
+            let a = vec1![1u8, 2, 4, 3, 5, 0];
+            let groups = a.chunk_by_ref(|l, r| l < r);
+            assert_eq!(groups, vec1![&[1u8, 2, 4][..], &[3, 5], &[0]]);
+
+            let groups = a.chunk_by_ref(|_, _| true);
+            assert_eq!(groups, vec1![&a[..]]);
+        }
+
+        #[test]
+        fn chunk_by_ref_mut() {
+            let mut a = vec1![1u8, 1, 2, 3, 3];
+            for group in a.chunk_by_ref_mut(|l, r| l == r) {
+                *group.first_mut() += 1;
+                let len = group.len() as u8;
+                group.iter_mut().for_each(|v| *v *= len);
+            }
+            assert_eq!(a, &[4u8, 2, 3, 8, 6]);
+        }
+
+        #[test]
+        fn group_by_key() {
+            let a = vec1![(1u8, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+            let groups = a.group_by_key(|v| v.0);
+            assert_eq!(
+                groups,
+                vec1![
+                    (1u8, vec1![(1u8, 'a'), (1, 'b')]),
+                    (2, vec1![(2, 'c')]),
+                    (1, vec1![(1, 'd')]),
+                ]
+            );
+        }
+
+        #[test]
+        fn chunks1() {
+            let a = vec1![1u8, 2, 3, 4, 5];
+            let size = NonZeroUsize::new(2).unwrap();
+            let mut iter = a.chunks1(size);
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.first_chunk(), &[1u8, 2][..]);
+            assert_eq!(iter.next().unwrap(), &[1u8, 2][..]);
+            assert_eq!(iter.next_back().unwrap(), &[5u8][..]);
+            assert_eq!(*iter.next().unwrap().last(), 4);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.first_chunk(), &[1u8, 2][..]);
+
+            let size = NonZeroUsize::new(10).unwrap();
+            let iter = a.chunks1(size);
+            assert_eq!(iter.first_chunk(), &a[..]);
+            assert_eq!(iter.collect::<Vec<_>>(), [&a[..]]);
+        }
+
+        #[test]
+        fn rchunks1() {
+            let a = vec1![1u8, 2, 3, 4, 5];
+            let size = NonZeroUsize::new(2).unwrap();
+            let iter = a.rchunks1(size);
+            assert_eq!(iter.first_chunk(), &[4u8, 5][..]);
+            let chunks = iter.map(Slice1::as_slice).collect::<Vec<_>>();
+            assert_eq!(chunks, [&[4u8, 5][..], &[2, 3], &[1]]);
+
+            let size = NonZeroUsize::new(10).unwrap();
+            assert_eq!(a.rchunks1(size).first_chunk(), &a[..]);
+        }
+
+        #[test]
+        fn chunks_exact1() {
+            let a = vec1![1u8, 2, 3, 4, 5];
+            let size = NonZeroUsize::new(2).unwrap();
+            let mut iter = a.chunks_exact1(size);
+            assert_eq!(iter.remainder(), &[5u8]);
+            assert_eq!(iter.next().unwrap(), &[1u8, 2][..]);
+            assert_eq!(iter.next().unwrap(), &[3u8, 4][..]);
+            assert_eq!(iter.next(), None);
+
+            let size = NonZeroUsize::new(6).unwrap();
+            let mut iter = a.chunks_exact1(size);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.remainder(), &a[..]);
+        }
+
+        #[test]
+        fn windows1() {
+            let a = vec1![1u8, 2, 3];
+            let size = NonZeroUsize::new(2).unwrap();
+            let windows = a.windows1(size).map(Slice1::as_slice).collect::<Vec<_>>();
+            assert_eq!(windows, [&[1u8, 2][..], &[2, 3]]);
+
+            let size = NonZeroUsize::new(4).unwrap();
+            assert_eq!(a.windows1(size).next(), None);
+        }
+
+        #[test]
+        fn slice_iter_wrappers_clone_without_t_clone() {
+            struct NotClone;
+            let a = vec1![NotClone, NotClone, NotClone];
+            let size = NonZeroUsize::new(2).unwrap();
+            let iter = a.chunks1(size);
+            assert_eq!(iter.clone().count(), 2);
+            assert_eq!(a.rchunks1(size).clone().count(), 2);
+            assert_eq!(a.chunks_exact1(size).clone().count(), 1);
+            assert_eq!(a.windows1(size).clone().count(), 2);
+        }
+
+        #[test]
+        fn with_vec_mut() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            let res = a.with_vec_mut(
+                || 0,
+                |v| {
+                    v.retain(|v| *v % 2 == 0);
+                    v.len()
+                },
+            );
+            assert_eq!(res, Ok(2));
+            assert_eq!(a, vec1![2u8, 4]);
+
+            let res = a.with_vec_mut(|| 10, |v| v.clear());
+            assert_eq!(res, Err(Size0Error));
+            assert_eq!(a, vec1![10u8]);
+        }
+
+        #[test]
+        fn with_vec_mut_restores_on_panic() {
+            let mut a = vec1![1u8, 2];
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                a.with_vec_mut(
+                    || 7,
+                    |v| {
+                        v.clear();
+                        panic!("fail");
+                    },
+                )
+            }));
+            assert!(res.is_err());
+            assert_eq!(a, vec1![7u8]);
+        }
+
+        #[test]
+        fn vec_mut_guard() {
+            let mut a = vec1![1u8, 2, 3];
+            {
+                let mut guard = a.vec_mut_guard(|| 0);
+                guard.pop();
+                assert_eq!(guard.len(), 2);
+            }
+            assert_eq!(a, vec1![1u8, 2]);
+
+            {
+                let mut guard = a.vec_mut_guard(|| 5);
+                guard.clear();
+                assert!(guard.is_empty());
+            }
+            assert_eq!(a, vec1![5u8]);
+
+            let mut guard = a.vec_mut_guard(|| 9);
+            guard.push(6);
+            assert_eq!(guard.finish(), Ok(()));
+            assert_eq!(a, vec1![5u8, 6]);
+
+            let mut guard = a.vec_mut_guard(|| 9);
+            guard.truncate(0);
+            assert_eq!(guard.finish(), Err(Size0Error));
+            assert_eq!(a, vec1![9u8]);
+        }
+
+        #[test]
+        fn vec_mut_guard_leaked() {
+            let mut a = vec1![1u8, 2, 3];
+            let mut guard = a.vec_mut_guard(|| 7);
+            guard.push(4);
+            core::mem::forget(guard);
+            assert_eq!(a, vec1![1u8, 2, 3, 4]);
+
+            // like leaking a `Drain` this can leave the vector empty
+            let mut guard = a.vec_mut_guard(|| 7);
+            guard.clear();
+            core::mem::forget(guard);
+            assert!(a.is_empty());
+        }
+
+        #[test]
+        fn with_vec_mut_calls_fallback_lazily_and_keeps_allocation() {
+            let mut a = Vec1::with_capacity(1u8, 8);
+            let ptr = a.as_ptr();
+            let res = a.with_vec_mut(|| panic!("fallback not needed"), |v| v.push(2));
+            assert_eq!(res, Ok(()));
+            assert_eq!(a, [1u8, 2]);
+            assert_eq!((a.as_ptr(), a.capacity()), (ptr, 8));
+        }
+
+        #[test]
+        fn split_into_chunks() {
+            let size = NonZeroUsize::new(2).unwrap();
+            let chunks = vec1![1u8, 2, 3, 4].split_into_chunks(size);
+            assert_eq!(chunks, vec1![vec1![1u8, 2], vec1![3, 4]]);
+
+            let chunks = vec1![1u8, 2, 3].split_into_chunks(size);
+            assert_eq!(chunks, vec1![vec1![1u8, 2], vec1![3]]);
+
+            let chunks = vec1![1u8].split_into_chunks(size);
+            assert_eq!(chunks, vec1![vec1![1u8]]);
+        }
+
+        #[test]
+        fn group_by_key_ref() {
+            let a = vec1![10u8, 12, 21, 25, 31];
+            let groups = a.group_by_key_ref(|v| *v / 10);
+            let groups = groups.mapped(|(key, group)| (key, group.as_slice()));
+            assert_eq!(
+                groups,
+                vec1![(1u8, &[10u8, 12][..]), (2, &[21, 25]), (3, &[31])]
+            );
+        }
+
         mod AsMut {
             use crate::*;
 
@@ -1938,6 +3524,207 @@ mod test {
             }
         }
 
+        #[cfg(feature = "arbitrary")]
+        mod arbitrary {
+            use crate::*;
+            use ::arbitrary::{Arbitrary, Unstructured};
+
+            /// Deterministic pseudo random bytes (xorshift).
+            fn bytes(seed: u64, len: usize) -> Vec<u8> {
+                let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+                (0..len)
+                    .map(|_| {
+                        state ^= state << 13;
+                        state ^= state >> 7;
+                        state ^= state << 17;
+                        state as u8
+                    })
+                    .collect()
+            }
+
+            #[test]
+            fn always_has_at_least_one_element() {
+                for seed in 0..500 {
+                    let data = bytes(seed, (seed % 64) as usize);
+                    let mut u = Unstructured::new(&data);
+                    let vec = Vec1::<u16>::arbitrary(&mut u).unwrap();
+                    assert!(!vec.is_empty());
+                    let vec =
+                        Vec1::<Vec1<u8>>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+                    assert!(!vec.is_empty());
+                    assert!(vec.iter().all(|inner| !inner.is_empty()));
+                }
+            }
+
+            #[test]
+            fn works_with_empty_input() {
+                let vec = Vec1::<u32>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+                assert_eq!(vec, [0]);
+                let vec = Vec1::<u32>::arbitrary_take_rest(Unstructured::new(&[])).unwrap();
+                assert_eq!(vec, [0]);
+            }
+
+            #[test]
+            fn consumes_input() {
+                let data = [1, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 0];
+                let mut u = Unstructured::new(&data);
+                let vec = Vec1::<u32>::arbitrary(&mut u).unwrap();
+                assert_eq!(vec, [1, 2]);
+                assert_eq!(u.len(), 4);
+            }
+
+            #[test]
+            fn size_hint() {
+                assert_eq!(Vec1::<u32>::size_hint(0), (4, None));
+                assert_eq!(Vec1::<(u8, u16)>::size_hint(0), (3, None));
+                assert_eq!(Vec1::<Vec1<u8>>::size_hint(0), (1, None));
+            }
+        }
+
+        #[cfg(feature = "quickcheck")]
+        mod quickcheck {
+            use crate::*;
+            use ::quickcheck::{Arbitrary, Gen, QuickCheck};
+
+            #[test]
+            fn respects_gen_size() {
+                let mut g = Gen::new(5);
+                for _ in 0..200 {
+                    let vec = Vec1::<u8>::arbitrary(&mut g);
+                    assert!((1..=5).contains(&vec.len()));
+                }
+                let mut g = Gen::new(0);
+                assert_eq!(Vec1::<u8>::arbitrary(&mut g).len(), 1);
+            }
+
+            #[test]
+            fn shrinks_length_first() {
+                let vec = vec1![1u8, 2, 3, 4];
+                let lens = vec.shrink().map(|vec| vec.len()).collect::<Vec<_>>();
+                assert!(!lens.is_empty());
+                assert!(lens.windows(2).all(|lens| lens[0] <= lens[1]));
+                assert_eq!(lens.first(), Some(&2));
+                assert_eq!(lens.last(), Some(&4));
+            }
+
+            #[test]
+            fn never_shrinks_to_empty() {
+                let shrunk = vec1![7u8].shrink().collect::<Vec<_>>();
+                assert!(shrunk.contains(&vec1![0]));
+                assert!(shrunk.iter().all(|vec| vec.len() == 1));
+
+                fn smallest(vec: Vec1<u32>) -> Vec1<u32> {
+                    match vec.shrink().next() {
+                        Some(vec) => smallest(vec),
+                        None => vec,
+                    }
+                }
+                assert_eq!(smallest(vec1![9, 8, 7, 6, 5]), [0]);
+            }
+
+            #[test]
+            fn property() {
+                fn prop(vec: Vec1<Vec1<u8>>) -> bool {
+                    !vec.is_empty() && vec.iter().all(|inner| !inner.is_empty())
+                }
+                QuickCheck::new().quickcheck(prop as fn(Vec1<Vec1<u8>>) -> bool);
+            }
+        }
+
+        #[cfg(feature = "borsh")]
+        mod borsh {
+            use crate::*;
+            use std::string::ToString;
+
+            #[test]
+            fn roundtrip() {
+                let vec = vec1![1u16, 2, 3];
+                let bytes = ::borsh::to_vec(&vec).unwrap();
+                assert_eq!(bytes, ::borsh::to_vec(&std::vec![1u16, 2, 3]).unwrap());
+                assert_eq!(::borsh::from_slice::<Vec1<u16>>(&bytes).unwrap(), vec);
+            }
+
+            #[test]
+            fn rejects_zero_length() {
+                let err = ::borsh::from_slice::<Vec1<u8>>(&[0, 0, 0, 0]).unwrap_err();
+                assert_eq!(err.to_string(), Size0Error::MESSAGE);
+            }
+
+            #[test]
+            fn bounds_malicious_length() {
+                let err = ::borsh::from_slice::<Vec1<u64>>(&[
+                    0xff, 0xff, 0xff, 0xff, 1, 0, 0, 0, 0, 0, 0, 0,
+                ]);
+                assert!(err.is_err());
+            }
+
+            #[test]
+            fn rejects_zero_sized_types() {
+                assert!(::borsh::to_vec(&vec1![()]).is_err());
+                assert!(::borsh::from_slice::<Vec1<()>>(&[0xff, 0xff, 0xff, 0xff]).is_err());
+            }
+        }
+
+        #[cfg(feature = "bincode")]
+        mod bincode {
+            use crate::*;
+            use ::bincode::{config, error::DecodeError};
+
+            #[test]
+            fn roundtrip() {
+                let vec = vec1![1u16, 2, 300];
+                let bytes = ::bincode::encode_to_vec(&vec, config::standard()).unwrap();
+                assert_eq!(
+                    bytes,
+                    ::bincode::encode_to_vec(std::vec![1u16, 2, 300], config::standard()).unwrap()
+                );
+                let (decoded, read) =
+                    ::bincode::decode_from_slice::<Vec1<u16>, _>(&bytes, config::standard())
+                        .unwrap();
+                assert_eq!(decoded, vec);
+                assert_eq!(read, bytes.len());
+            }
+
+            #[test]
+            fn borrow_decode() {
+                let bytes = ::bincode::encode_to_vec(vec1!["a", "bc"], config::standard()).unwrap();
+                let (decoded, _) = ::bincode::borrow_decode_from_slice::<Vec1<&str>, _>(
+                    &bytes,
+                    config::standard(),
+                )
+                .unwrap();
+                assert_eq!(decoded, ["a", "bc"]);
+            }
+
+            #[test]
+            fn rejects_zero_length() {
+                let err = ::bincode::decode_from_slice::<Vec1<u8>, _>(&[0], config::standard())
+                    .unwrap_err();
+                assert!(matches!(err, DecodeError::Other(msg) if msg == Size0Error::MESSAGE));
+            }
+
+            #[test]
+            fn bounds_malicious_length() {
+                // varint encoded `u64::MAX`
+                let bytes = [253, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 7];
+                let err = ::bincode::decode_from_slice::<Vec1<u64>, _>(&bytes, config::standard())
+                    .unwrap_err();
+                assert!(matches!(
+                    err,
+                    DecodeError::UnexpectedEnd { .. } | DecodeError::OutsideUsizeRange(_)
+                ));
+                let err = ::bincode::decode_from_slice::<Vec1<u64>, _>(
+                    &bytes,
+                    config::standard().with_limit::<1024>(),
+                )
+                .unwrap_err();
+                assert!(matches!(
+                    err,
+                    DecodeError::LimitExceeded | DecodeError::OutsideUsizeRange(_)
+                ));
+            }
+        }
+
         #[cfg(feature = "serde")]
         mod serde {
             use crate::*;
@@ -1945,7 +3732,59 @@ mod test {
             #[test]
             fn empty() {
                 let result: Result<Vec1<u8>, _> = serde_json::from_str("[]");
-                assert!(result.is_err());
+                assert_eq!(
+                    std::string::ToString::to_string(&result.unwrap_err()),
+                    "invalid length 0, expected a sequence with at least 1 element at line 1 column 2"
+                );
+            }
+
+            #[test]
+            fn postcard() {
+                let vec = vec1![1u32, 2, 300];
+                let bytes = ::postcard::to_allocvec(&vec).unwrap();
+                assert_eq!(
+                    bytes,
+                    ::postcard::to_allocvec(&std::vec![1u32, 2, 300]).unwrap()
+                );
+                assert_eq!(::postcard::from_bytes::<Vec1<u32>>(&bytes).unwrap(), vec);
+
+                assert!(::postcard::from_bytes::<Vec1<u32>>(&[0]).is_err());
+                // varint encoded `u32::MAX` as length prefix
+                let malicious = [0xff, 0xff, 0xff, 0xff, 0x0f, 1];
+                assert!(::postcard::from_bytes::<Vec1<u64>>(&malicious).is_err());
+            }
+
+            #[test]
+            fn borrowed_elements() {
+                let json = std::string::String::from(r#"["a", "bc"]"#);
+                let vec: Vec1<&str> = serde_json::from_str(&json).unwrap();
+                assert_eq!(vec, ["a", "bc"]);
+                assert_eq!(vec[1].as_ptr(), json[7..].as_ptr());
+            }
+
+            #[test]
+            fn caps_size_hint() {
+                use ::serde::de::{value::SeqDeserializer, Deserialize};
+
+                struct LyingIter(Option<u64>);
+
+                impl Iterator for LyingIter {
+                    type Item = u64;
+
+                    fn next(&mut self) -> Option<u64> {
+                        self.0.take()
+                    }
+
+                    fn size_hint(&self) -> (usize, Option<usize>) {
+                        (usize::MAX, Some(usize::MAX))
+                    }
+                }
+
+                let deserializer =
+                    SeqDeserializer::<_, ::serde::de::value::Error>::new(LyingIter(Some(3)));
+                let vec = Vec1::<u64>::deserialize(deserializer).unwrap();
+                assert_eq!(vec, [3]);
+                assert!(vec.capacity() <= 1024 * 1024 / 8);
             }
 
             #[test]
@@ -0,0 +1,274 @@
+//! `proptest` strategies, see the `proptest` feature.
+//!
+//! `Vec1` and `SmallVec1` also implement [`Arbitrary`], so `any::<Vec1<T>>()`
+//! works, too.
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use vec1::{proptest_support::vec1, Vec1};
+//!
+//! proptest! {
+//!     fn first_is_min_after_sort(mut vec in vec1(any::<u8>(), 1..10)) {
+//!         vec.sort();
+//!         prop_assert_eq!(vec.first(), vec.iter().min().unwrap());
+//!     }
+//! }
+//! # fn main() { first_is_min_after_sort(); }
+//! ```
+//!
+//! [`Arbitrary`]: ::proptest::arbitrary::Arbitrary
+
+use core::{fmt, marker::PhantomData};
+
+use alloc::vec::Vec;
+
+use ::proptest::{
+    collection::{self, SizeRange, VecStrategy, VecValueTree},
+    strategy::{NewTree, Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+
+use crate::Vec1;
+
+/// Creates a strategy generating `Vec1`s with elements from `element` and a length in `size`.
+///
+/// Shrinking removes elements down to the lower bound of `size` and then
+/// shrinks the remaining elements, i.e. with a lower bound of 1 it shrinks
+/// towards a one-element vector.
+///
+/// # Panics
+///
+/// If `size` includes 0 or is empty.
+pub fn vec1<S>(element: S, size: impl Into<SizeRange>) -> Vec1Strategy<S>
+where
+    S: Strategy,
+{
+    Vec1Strategy::new(element, size.into())
+}
+
+/// Creates a strategy generating `SmallVec1`s with elements from `element` and a length in `size`.
+///
+/// Works like [`vec1()`].
+///
+/// # Panics
+///
+/// If `size` includes 0 or is empty.
+#[cfg(feature = "smallvec-v1")]
+pub fn smallvec1<A, S>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> Vec1Strategy<S, crate::smallvec_v1::SmallVec1<A>>
+where
+    A: smallvec_v1_::Array<Item = S::Value>,
+    S: Strategy,
+{
+    Vec1Strategy::new(element, size.into())
+}
+
+/// Raises a lower bound of 0 to 1, used by the `Arbitrary` implementations.
+pub(crate) fn at_least_one(size: SizeRange) -> SizeRange {
+    let (start, end) = size.start_end_incl();
+    SizeRange::new(start.max(1)..=end.max(1))
+}
+
+/// Strategy to create `Vec1`s (or `SmallVec1`s) with a length in a certain range.
+///
+/// Created by [`vec1()`] or [`smallvec1()`].
+#[must_use = "strategies do nothing unless used"]
+pub struct Vec1Strategy<S, V = Vec1<<S as Strategy>::Value>>
+where
+    S: Strategy,
+{
+    inner: VecStrategy<S>,
+    _target: PhantomData<fn() -> V>,
+}
+
+impl<S, V> Vec1Strategy<S, V>
+where
+    S: Strategy,
+{
+    pub(crate) fn new(element: S, size: SizeRange) -> Self {
+        assert!(
+            size.start() > 0,
+            "Invalid use of size range including 0 for a non-empty collection: {:?}",
+            size
+        );
+        Vec1Strategy {
+            inner: collection::vec(element, size),
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<S, V> Clone for Vec1Strategy<S, V>
+where
+    S: Strategy + Clone,
+{
+    fn clone(&self) -> Self {
+        Vec1Strategy {
+            inner: self.inner.clone(),
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<S, V> fmt::Debug for Vec1Strategy<S, V>
+where
+    S: Strategy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Vec1Strategy").field(&self.inner).finish()
+    }
+}
+
+impl<S, V> Strategy for Vec1Strategy<S, V>
+where
+    S: Strategy,
+    V: TryFrom<Vec<S::Value>> + fmt::Debug,
+{
+    type Tree = Vec1ValueTree<S::Tree, V>;
+    type Value = V;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        Ok(Vec1ValueTree {
+            inner: self.inner.new_tree(runner)?,
+            _target: PhantomData,
+        })
+    }
+}
+
+/// `ValueTree` corresponding to [`Vec1Strategy`].
+pub struct Vec1ValueTree<T, V = Vec1<<T as ValueTree>::Value>>
+where
+    T: ValueTree,
+{
+    inner: VecValueTree<T>,
+    _target: PhantomData<fn() -> V>,
+}
+
+impl<T, V> Clone for Vec1ValueTree<T, V>
+where
+    T: ValueTree + Clone,
+{
+    fn clone(&self) -> Self {
+        Vec1ValueTree {
+            inner: self.inner.clone(),
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<T, V> fmt::Debug for Vec1ValueTree<T, V>
+where
+    T: ValueTree + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Vec1ValueTree").field(&self.inner).finish()
+    }
+}
+
+impl<T, V> ValueTree for Vec1ValueTree<T, V>
+where
+    T: ValueTree,
+    V: TryFrom<Vec<T::Value>> + fmt::Debug,
+{
+    type Value = V;
+
+    fn current(&self) -> V {
+        V::try_from(self.inner.current())
+            .unwrap_or_else(|_| unreachable!("size range of Vec1Strategy starts at 1"))
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.inner.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.inner.complicate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::proptest::{collection::size_range, prelude::*};
+    use std::format;
+
+    #[test]
+    #[should_panic(expected = "size range including 0")]
+    fn rejects_size_range_including_0() {
+        let _ = vec1(any::<u8>(), 0..10);
+    }
+
+    #[test]
+    fn shrinks_to_one_element() {
+        let strategy = vec1(any::<u8>(), 1..20);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            while tree.simplify() {
+                assert!(tree.current().len() < 20);
+            }
+            assert_eq!(tree.current(), [0]);
+        }
+    }
+
+    #[test]
+    fn respects_size_range() {
+        let strategy = vec1(any::<u8>(), 3..=5);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            assert!((3..=5).contains(&tree.current().len()));
+            while tree.simplify() {}
+            assert_eq!(tree.current().len(), 3);
+        }
+    }
+
+    #[test]
+    fn arbitrary_raises_lower_bound() {
+        let strategy = any_with::<Vec1<u8>>((size_range(0..=0), ()));
+        let mut runner = TestRunner::deterministic();
+        let tree = strategy.new_tree(&mut runner).unwrap();
+        assert_eq!(tree.current().len(), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_vec1(vec in any::<Vec1<u8>>()) {
+            prop_assert!(vec.len() < 100);
+        }
+
+        #[test]
+        fn vec1_strategy(vec in vec1(1..10u8, 2..4)) {
+            prop_assert!((2..4).contains(&vec.len()));
+            prop_assert!(vec.iter().all(|v| (1..10).contains(v)));
+        }
+    }
+
+    #[cfg(feature = "smallvec-v1")]
+    mod smallvec_v1 {
+        use super::super::*;
+        use crate::smallvec_v1::SmallVec1;
+        use ::proptest::prelude::*;
+        use std::format;
+
+        #[test]
+        fn shrinks_to_one_element() {
+            let strategy = smallvec1::<[u8; 4], _>(any::<u8>(), 1..20);
+            let mut runner = TestRunner::deterministic();
+            for _ in 0..64 {
+                let mut tree = strategy.new_tree(&mut runner).unwrap();
+                while tree.simplify() {}
+                assert_eq!(tree.current().as_slice(), &[0]);
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn arbitrary_smallvec1(vec in any::<SmallVec1<[u8; 4]>>()) {
+                prop_assert!(vec.len() < 100);
+            }
+        }
+    }
+}
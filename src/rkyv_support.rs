@@ -0,0 +1,228 @@
+//! Zero-copy archiving with `rkyv`, see the `rkyv` feature.
+//!
+//! `Vec1<T>` is archived as [`ArchivedVec1`], which has the same layout as
+//! `rkyv::vec::ArchivedVec` but guarantees to not be empty. Validating it
+//! (e.g. through `rkyv::access`) rejects empty archived vectors.
+//!
+//! ```
+//! use rkyv::rancor::Error;
+//! use vec1::{rkyv_support::ArchivedVec1, vec1, Vec1};
+//!
+//! let bytes = rkyv::to_bytes::<Error>(&vec1![1u32, 2, 3]).unwrap();
+//! let archived = rkyv::access::<ArchivedVec1<rkyv::Archived<u32>>, Error>(&bytes).unwrap();
+//! assert_eq!(*archived.last(), 3);
+//!
+//! let empty = rkyv::to_bytes::<Error>(&Vec::<u32>::new()).unwrap();
+//! assert!(rkyv::access::<ArchivedVec1<rkyv::Archived<u32>>, Error>(&empty).is_err());
+//! ```
+
+use core::{fmt, ops::Deref};
+
+use alloc::vec::Vec;
+
+use ::rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Source},
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+use crate::{Size0Error, Vec1};
+
+/// An archived [`Vec1`].
+///
+/// Derefs to a slice and like `Vec1` has infallible [`first`](ArchivedVec1::first)
+/// and [`last`](ArchivedVec1::last) methods.
+#[repr(transparent)]
+pub struct ArchivedVec1<T>(ArchivedVec<T>);
+
+// Safety: `repr(transparent)` over a `Portable` type.
+unsafe impl<T> Portable for ArchivedVec1<T> where ArchivedVec<T>: Portable {}
+
+impl<T> ArchivedVec1<T> {
+    /// Returns a reference to the first element.
+    pub fn first(&self) -> &T {
+        // Only validated archives are guaranteed to not be empty.
+        self.0.as_slice().first().unwrap()
+    }
+
+    /// Returns a reference to the last element.
+    pub fn last(&self) -> &T {
+        self.0.as_slice().last().unwrap()
+    }
+
+    /// Returns the archived elements as slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    /// Returns the underlying `ArchivedVec`.
+    pub fn as_archived_vec(&self) -> &ArchivedVec<T> {
+        &self.0
+    }
+}
+
+impl<T> Deref for ArchivedVec1<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for ArchivedVec1<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> fmt::Debug for ArchivedVec1<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, U> PartialEq<ArchivedVec1<U>> for ArchivedVec1<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &ArchivedVec1<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T> Eq for ArchivedVec1<T> where T: Eq {}
+
+impl<T, U> PartialEq<Vec1<U>> for ArchivedVec1<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec1<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, U> PartialEq<[U]> for ArchivedVec1<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+// Safety: Checks the `ArchivedVec` and then that it's not empty.
+unsafe impl<T, C> CheckBytes<C> for ArchivedVec1<T>
+where
+    ArchivedVec<T>: CheckBytes<C>,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        let inner = value.cast::<ArchivedVec<T>>();
+        ArchivedVec::<T>::check_bytes(inner, context)?;
+        if (*inner).is_empty() {
+            return Err(C::Error::new(Size0Error));
+        }
+        Ok(())
+    }
+}
+
+impl<T> Archive for Vec1<T>
+where
+    T: Archive,
+{
+    type Archived = ArchivedVec1<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // Safety: `ArchivedVec1` is `repr(transparent)` over `ArchivedVec`.
+        let out = unsafe { out.cast_unchecked::<ArchivedVec<T::Archived>>() };
+        ArchivedVec::resolve_from_slice(self.as_slice(), resolver, out);
+    }
+}
+
+impl<T, S> Serialize<S> for Vec1<T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<T, D> Deserialize<Vec1<T>, D> for ArchivedVec1<T::Archived>
+where
+    T: Archive,
+    ArchivedVec<T::Archived>: Deserialize<Vec<T>, D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Vec1<T>, D::Error> {
+        // Archives accessed without validation might still be empty.
+        Vec1::try_from_vec(self.0.deserialize(deserializer)?).map_err(D::Error::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+    use ::rkyv::{rancor::Error, Archived};
+
+    type Archived1 = ArchivedVec1<Archived<u32>>;
+
+    #[test]
+    fn roundtrip() {
+        let vec = vec1![1u32, 2, 3];
+        let bytes = ::rkyv::to_bytes::<Error>(&vec).unwrap();
+        let archived = ::rkyv::access::<Archived1, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), 3);
+        assert_eq!(*archived.first(), 1);
+        assert_eq!(*archived.last(), 3);
+        assert_eq!(*archived, vec1![1u32, 2, 3]);
+        let vec2: Vec1<u32> = ::rkyv::deserialize::<Vec1<u32>, Error>(archived).unwrap();
+        assert_eq!(vec2, vec);
+        assert_eq!(::rkyv::from_bytes::<Vec1<u32>, Error>(&bytes).unwrap(), vec);
+    }
+
+    #[test]
+    fn same_layout_as_vec() {
+        let vec_bytes = ::rkyv::to_bytes::<Error>(&std::vec![4u16, 5]).unwrap();
+        let vec1_bytes = ::rkyv::to_bytes::<Error>(&vec1![4u16, 5]).unwrap();
+        assert_eq!(vec_bytes.as_slice(), vec1_bytes.as_slice());
+    }
+
+    #[test]
+    fn rejects_empty_archive() {
+        let bytes = ::rkyv::to_bytes::<Error>(&Vec::<u32>::new()).unwrap();
+        assert!(::rkyv::access::<ArchivedVec<Archived<u32>>, Error>(&bytes).is_ok());
+        assert!(::rkyv::access::<Archived1, Error>(&bytes).is_err());
+        assert!(::rkyv::from_bytes::<Vec1<u32>, Error>(&bytes).is_err());
+    }
+
+    #[test]
+    fn deserialize_unchecked_empty_archive_fails() {
+        let bytes = ::rkyv::to_bytes::<Error>(&Vec::<u32>::new()).unwrap();
+        // Safety: Valid `ArchivedVec1` apart from being empty, which is checked on deserialization.
+        let archived = unsafe { ::rkyv::access_unchecked::<Archived1>(&bytes) };
+        assert!(::rkyv::deserialize::<Vec1<u32>, Error>(archived).is_err());
+    }
+
+    #[test]
+    fn nested() {
+        let vec = vec1![vec1![1u8], vec1![2, 3]];
+        let bytes = ::rkyv::to_bytes::<Error>(&vec).unwrap();
+        let archived = ::rkyv::access::<ArchivedVec1<ArchivedVec1<u8>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.last().as_slice(), &[2, 3]);
+        assert_eq!(
+            ::rkyv::from_bytes::<Vec1<Vec1<u8>>, Error>(&bytes).unwrap(),
+            vec
+        );
+    }
+}
@@ -0,0 +1,525 @@
+//! Additional (opt-in) serde support for [`Vec1`](crate::Vec1) and `SmallVec1`.
+//!
+//! The default `Serialize`/`Deserialize` implementations (de-)serialize a non-empty
+//! sequence. The adapters in this module additionally accept a single value in place
+//! of a sequence with exactly one element, e.g. both `server: "a"` and
+//! `server: ["a", "b"]`.
+//!
+//! This is implemented using an untagged representation, so it only works with
+//! self-describing formats (e.g. JSON, YAML, TOML). If the element type itself
+//! deserializes from a sequence, sequences are always treated as sequence of elements.
+
+use core::ops::{Deref, DerefMut};
+
+use alloc::vec::Vec;
+
+use ::serde::{
+    de::{Deserialize, Deserializer, Error as _},
+    ser::{Serialize, Serializer},
+};
+
+use crate::Size0Error;
+
+/// Non-empty vectors which can be used with the adapters in this module.
+///
+/// This is implemented for `Vec1` and `SmallVec1`.
+pub trait Vec1Like: Sized {
+    /// The type of the elements.
+    type Item;
+
+    /// Tries to create an instance from a `Vec`, failing if it's empty.
+    fn try_from_vec(vec: Vec<Self::Item>) -> Result<Self, Size0Error>;
+
+    /// Returns the elements as a slice, which is never empty.
+    fn as_slice(&self) -> &[Self::Item];
+}
+
+/// Use with `#[serde(with = "vec1::serde_ext::one_or_many")]` to accept a single value or a non-empty sequence.
+///
+/// This always serializes as sequence, use
+/// [`one_or_many::single_if_one`]
+/// to serialize vectors with exactly one element as single value.
+///
+/// # Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use vec1::{vec1, Vec1};
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "vec1::serde_ext::one_or_many")]
+///     server: Vec1<String>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"server": "a"}"#).unwrap();
+/// assert_eq!(config.server, vec1!["a".to_owned()]);
+/// let config: Config = serde_json::from_str(r#"{"server": ["a", "b"]}"#).unwrap();
+/// assert_eq!(config.server, ["a", "b"]);
+/// ```
+pub mod one_or_many {
+    use super::*;
+
+    /// Serializes the vector as sequence.
+    pub fn serialize<V, S>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: Vec1Like,
+        V::Item: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(value.as_slice())
+    }
+
+    /// Deserializes a single value or a non-empty sequence.
+    pub fn deserialize<'de, V, D>(deserializer: D) -> Result<V, D::Error>
+    where
+        V: Vec1Like,
+        V::Item: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let vec = match OneOrManyRepr::deserialize(deserializer)? {
+            OneOrManyRepr::Many(vec) => vec,
+            OneOrManyRepr::One(value) => alloc::vec![value],
+        };
+        V::try_from_vec(vec)
+            .map_err(|Size0Error| D::Error::invalid_length(0, &"a value or a non-empty sequence"))
+    }
+
+    /// Like [`one_or_many`](super) but serializes vectors with exactly one element as single value.
+    pub mod single_if_one {
+        use super::*;
+
+        /// Serializes the vector as single value if it has exactly one element, as sequence otherwise.
+        pub fn serialize<V, S>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            V: Vec1Like,
+            V::Item: Serialize,
+            S: Serializer,
+        {
+            match value.as_slice() {
+                [single] => single.serialize(serializer),
+                slice => serializer.collect_seq(slice),
+            }
+        }
+
+        /// Deserializes a single value or a non-empty sequence.
+        pub fn deserialize<'de, V, D>(deserializer: D) -> Result<V, D::Error>
+        where
+            V: Vec1Like,
+            V::Item: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            super::deserialize(deserializer)
+        }
+    }
+
+    #[derive(::serde::Deserialize)]
+    #[serde(untagged)]
+    enum OneOrManyRepr<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+}
+
+/// Use with `#[serde(with = "vec1::serde_ext::nonempty_vec")]` on `Vec<T>` fields to reject empty sequences.
+///
+/// This is useful for fields which can't be changed to `Vec1<T>`, e.g. for
+/// API compatibility. Deserializing an empty sequence fails with an
+/// "invalid length 0, expected a non-empty sequence" error. Serialization is
+/// the same as for `Vec<T>`.
+///
+/// Reporting the path of the field which was empty is out of scope of these
+/// adapters, the error message only describes the value. As errors are reported
+/// through the `Deserializer` of the field, tools like `serde_path_to_error`
+/// can be used to add the path.
+///
+/// # Example
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "vec1::serde_ext::nonempty_vec")]
+///     servers: Vec<String>,
+/// }
+///
+/// assert!(serde_json::from_str::<Config>(r#"{"servers": ["a"]}"#).is_ok());
+/// assert!(serde_json::from_str::<Config>(r#"{"servers": []}"#).is_err());
+/// ```
+pub mod nonempty_vec {
+    use super::*;
+
+    /// Serializes the `Vec` like `Vec<T>` does.
+    pub fn serialize<T, S>(value: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(value)
+    }
+
+    /// Deserializes a `Vec`, failing if it is empty.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let vec = Vec::deserialize(deserializer)?;
+        reject_empty::<D, _>(vec.is_empty(), vec, "a non-empty sequence")
+    }
+}
+
+/// Use with `#[serde(with = "vec1::serde_ext::nonempty_string")]` on `String` fields to reject empty strings.
+///
+/// See [`nonempty_vec`] for details.
+pub mod nonempty_string {
+    use super::*;
+    use alloc::string::String;
+
+    /// Serializes the `String` like `String` does.
+    pub fn serialize<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(value)
+    }
+
+    /// Deserializes a `String`, failing if it is empty.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        reject_empty::<D, _>(string.is_empty(), string, "a non-empty string")
+    }
+}
+
+/// Use with `#[serde(with = "vec1::serde_ext::nonempty_btreemap")]` on `BTreeMap` fields to reject empty maps.
+///
+/// See [`nonempty_vec`] for details.
+pub mod nonempty_btreemap {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    /// Serializes the map like `BTreeMap` does.
+    pub fn serialize<K, V, S>(value: &BTreeMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_map(value)
+    }
+
+    /// Deserializes a `BTreeMap`, failing if it is empty.
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<BTreeMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Ord,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let map = BTreeMap::deserialize(deserializer)?;
+        reject_empty::<D, _>(map.is_empty(), map, "a non-empty map")
+    }
+}
+
+/// Use with `#[serde(with = "vec1::serde_ext::nonempty_hashmap")]` on `HashMap` fields to reject empty maps.
+///
+/// See [`nonempty_vec`] for details.
+#[cfg(feature = "std")]
+pub mod nonempty_hashmap {
+    use super::*;
+    use core::hash::{BuildHasher, Hash};
+    use std::collections::HashMap;
+
+    /// Serializes the map like `HashMap` does.
+    pub fn serialize<K, V, H, S>(value: &HashMap<K, V, H>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_map(value)
+    }
+
+    /// Deserializes a `HashMap`, failing if it is empty.
+    pub fn deserialize<'de, K, V, H, D>(deserializer: D) -> Result<HashMap<K, V, H>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        H: BuildHasher + Default,
+        D: Deserializer<'de>,
+    {
+        let map = HashMap::deserialize(deserializer)?;
+        reject_empty::<D, _>(map.is_empty(), map, "a non-empty map")
+    }
+}
+
+fn reject_empty<'de, D, T>(is_empty: bool, value: T, expected: &'static str) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if is_empty {
+        Err(D::Error::invalid_length(0, &expected))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Use with `#[serde(default, deserialize_with = "vec1::serde_ext::empty_as_none")]`
+/// on `Option<Vec1<T>>` fields to treat an empty sequence like `null`.
+///
+/// # Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use vec1::Vec1;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(default, deserialize_with = "vec1::serde_ext::empty_as_none")]
+///     servers: Option<Vec1<String>>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"servers": []}"#).unwrap();
+/// assert_eq!(config.servers, None);
+/// let config: Config = serde_json::from_str(r#"{"servers": ["a"]}"#).unwrap();
+/// assert_eq!(config.servers.unwrap(), ["a"]);
+/// ```
+pub fn empty_as_none<'de, V, D>(deserializer: D) -> Result<Option<V>, D::Error>
+where
+    V: Vec1Like,
+    V::Item: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let vec = Option::<Vec<V::Item>>::deserialize(deserializer)?;
+    Ok(vec.and_then(|vec| V::try_from_vec(vec).ok()))
+}
+
+/// Wrapper around a `Vec1`/`SmallVec1` which deserializes from a single value or a non-empty sequence.
+///
+/// If `SINGLE_IF_ONE` is `true` vectors with exactly one element are serialized
+/// as single value (see [`SingleIfOne`]), else they are always serialized as sequence.
+///
+/// # Example
+///
+/// ```
+/// use vec1::{vec1, Vec1, serde_ext::{OneOrMany, SingleIfOne}};
+///
+/// let servers: OneOrMany<Vec1<u8>> = serde_json::from_str("1").unwrap();
+/// assert_eq!(*servers, vec1![1]);
+/// assert_eq!(serde_json::to_string(&servers).unwrap(), "[1]");
+///
+/// let servers = SingleIfOne::new(vec1![1u8]);
+/// assert_eq!(serde_json::to_string(&servers).unwrap(), "1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OneOrMany<V, const SINGLE_IF_ONE: bool = false>(pub V);
+
+/// A [`OneOrMany`] which serializes vectors with exactly one element as single value.
+pub type SingleIfOne<V> = OneOrMany<V, true>;
+
+impl<V, const SINGLE_IF_ONE: bool> OneOrMany<V, SINGLE_IF_ONE> {
+    /// Wraps given vector.
+    pub fn new(vec: V) -> Self {
+        OneOrMany(vec)
+    }
+
+    /// Returns the wrapped vector.
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<V, const SINGLE_IF_ONE: bool> From<V> for OneOrMany<V, SINGLE_IF_ONE>
+where
+    V: Vec1Like,
+{
+    fn from(vec: V) -> Self {
+        OneOrMany(vec)
+    }
+}
+
+impl<V, const SINGLE_IF_ONE: bool> Deref for OneOrMany<V, SINGLE_IF_ONE> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<V, const SINGLE_IF_ONE: bool> DerefMut for OneOrMany<V, SINGLE_IF_ONE> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<V, const SINGLE_IF_ONE: bool> Serialize for OneOrMany<V, SINGLE_IF_ONE>
+where
+    V: Vec1Like,
+    V::Item: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if SINGLE_IF_ONE {
+            one_or_many::single_if_one::serialize(&self.0, serializer)
+        } else {
+            one_or_many::serialize(&self.0, serializer)
+        }
+    }
+}
+
+impl<'de, V, const SINGLE_IF_ONE: bool> Deserialize<'de> for OneOrMany<V, SINGLE_IF_ONE>
+where
+    V: Vec1Like,
+    V::Item: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        one_or_many::deserialize(deserializer).map(OneOrMany)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use ::serde::{Deserialize, Serialize};
+    use std::string::{String, ToString};
+
+    use super::{OneOrMany, SingleIfOne};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_ext::one_or_many")]
+        always_seq: Vec1<u8>,
+        #[serde(with = "crate::serde_ext::one_or_many::single_if_one")]
+        single_if_one: Vec1<String>,
+    }
+
+    #[test]
+    fn one_or_many() {
+        let config: Config =
+            serde_json::from_str(r#"{"always_seq": 1, "single_if_one": ["a", "b"]}"#).unwrap();
+        assert_eq!(config.always_seq, vec1![1u8]);
+        assert_eq!(config.single_if_one, ["a", "b"]);
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            r#"{"always_seq":[1],"single_if_one":["a","b"]}"#
+        );
+
+        let config: Config =
+            serde_json::from_str(r#"{"always_seq": [1, 2], "single_if_one": ["a"]}"#).unwrap();
+        assert_eq!(config.always_seq, vec1![1u8, 2]);
+        assert_eq!(config.single_if_one, ["a"]);
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            r#"{"always_seq":[1,2],"single_if_one":"a"}"#
+        );
+    }
+
+    #[test]
+    fn one_or_many_rejects_empty_seq() {
+        let err = serde_json::from_str::<Config>(r#"{"always_seq": [], "single_if_one": "a"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid length 0"));
+    }
+
+    #[test]
+    fn one_or_many_wrapper() {
+        let vec: OneOrMany<Vec1<u8>> = serde_json::from_str("3").unwrap();
+        assert_eq!(vec.into_inner(), vec1![3u8]);
+        let vec: OneOrMany<Vec1<u8>> = serde_json::from_str("[3, 4]").unwrap();
+        assert_eq!(*vec, vec1![3u8, 4]);
+        assert_eq!(serde_json::to_string(&vec).unwrap(), "[3,4]");
+        assert!(serde_json::from_str::<OneOrMany<Vec1<u8>>>("[]").is_err());
+
+        let vec: SingleIfOne<Vec1<u8>> = serde_json::from_str("[3]").unwrap();
+        assert_eq!(serde_json::to_string(&vec).unwrap(), "3");
+        let vec = SingleIfOne::new(vec1![3u8, 4]);
+        assert_eq!(serde_json::to_string(&vec).unwrap(), "[3,4]");
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Compat {
+        #[serde(with = "crate::serde_ext::nonempty_vec")]
+        vec: Vec<u8>,
+        #[serde(with = "crate::serde_ext::nonempty_string")]
+        string: String,
+        #[serde(with = "crate::serde_ext::nonempty_btreemap")]
+        btree: std::collections::BTreeMap<String, u8>,
+        #[serde(with = "crate::serde_ext::nonempty_hashmap")]
+        hash: std::collections::HashMap<String, u8>,
+        #[serde(default, deserialize_with = "crate::serde_ext::empty_as_none")]
+        opt: Option<Vec1<u8>>,
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn nonempty_adapters() {
+        let json = r#"{"vec": [1], "string": "a", "btree": {"a": 1}, "hash": {"b": 2}, "opt": []}"#;
+        let compat: Compat = serde_json::from_str(json).unwrap();
+        assert_eq!(compat.vec, [1]);
+        assert_eq!(compat.string, "a");
+        assert_eq!(compat.btree["a"], 1);
+        assert_eq!(compat.hash["b"], 2);
+        assert_eq!(compat.opt, None);
+        assert_eq!(
+            serde_json::to_string(&compat).unwrap(),
+            r#"{"vec":[1],"string":"a","btree":{"a":1},"hash":{"b":2},"opt":null}"#
+        );
+
+        let json = r#"{"vec": [1], "string": "a", "btree": {"a": 1}, "hash": {"b": 2}}"#;
+        let compat: Compat = serde_json::from_str(json).unwrap();
+        assert_eq!(compat.opt, None);
+
+        let json =
+            r#"{"vec": [1], "string": "a", "btree": {"a": 1}, "hash": {"b": 2}, "opt": [3]}"#;
+        let compat: Compat = serde_json::from_str(json).unwrap();
+        assert_eq!(compat.opt, Some(vec1![3]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn nonempty_adapters_reject_empty() {
+        for (field, expected, json) in [
+            (
+                "vec",
+                "a non-empty sequence",
+                r#"{"vec": [], "string": "a", "btree": {"a": 1}, "hash": {"b": 2}}"#,
+            ),
+            (
+                "string",
+                "a non-empty string",
+                r#"{"vec": [1], "string": "", "btree": {"a": 1}, "hash": {"b": 2}}"#,
+            ),
+            (
+                "btree",
+                "a non-empty map",
+                r#"{"vec": [1], "string": "a", "btree": {}, "hash": {"b": 2}}"#,
+            ),
+            (
+                "hash",
+                "a non-empty map",
+                r#"{"vec": [1], "string": "a", "btree": {"a": 1}, "hash": {}}"#,
+            ),
+        ] {
+            let deserializer = &mut serde_json::Deserializer::from_str(json);
+            let err = serde_path_to_error::deserialize::<_, Compat>(deserializer).unwrap_err();
+            assert_eq!(err.path().to_string(), field);
+            let message = std::format!("invalid length 0, expected {expected}");
+            assert!(err.inner().to_string().starts_with(&message));
+        }
+    }
+
+    #[cfg(feature = "smallvec-v1")]
+    #[test]
+    fn one_or_many_smallvec1() {
+        use crate::smallvec_v1::SmallVec1;
+
+        let vec: OneOrMany<SmallVec1<[u8; 2]>> = serde_json::from_str("7").unwrap();
+        assert_eq!(vec.as_slice(), &[7]);
+        let vec: SingleIfOne<SmallVec1<[u8; 2]>> = serde_json::from_str("[7, 8, 9]").unwrap();
+        assert_eq!(vec.as_slice(), &[7, 8, 9]);
+        assert_eq!(serde_json::to_string(&vec).unwrap(), "[7,8,9]");
+    }
+}
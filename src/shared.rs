@@ -20,6 +20,59 @@ pub(crate) fn range_covers_slice(
     (covers_start && covers_end, oob_start || oob_end)
 }
 
+/// Returns the `(start, end)` indices of the range, panicking like slice indexing does
+/// if the range is invalid or out of bounds.
+pub(crate) fn range_to_bounds(range: &impl RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(idx) => *idx,
+        Bound::Excluded(idx) => idx
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(idx) => idx
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(idx) => *idx,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end,
+        "slice index starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end index {} out of range for slice of length {}",
+        end,
+        len
+    );
+    (start, end)
+}
+
+/// Returns the capacity to pre-allocate for `len` elements read from untrusted input.
+///
+/// Length prefixes can't be trusted, so don't pre-allocate more than 1MiB.
+#[cfg(any(feature = "serde", feature = "borsh", feature = "bincode"))]
+pub(crate) fn cautious_capacity<T>(len: usize) -> usize {
+    const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+    len.min(MAX_PREALLOC_BYTES / core::mem::size_of::<T>().max(1))
+}
+
+/// Overwrites the memory of `len` elements starting at `ptr` with zeros.
+///
+/// # Safety
+///
+/// The memory must be part of a single allocation, valid for writes and
+/// must not contain any live values.
+#[cfg(feature = "zeroize")]
+pub(crate) unsafe fn zeroize_uninit<T>(ptr: *mut T, len: usize) {
+    use ::zeroize::Zeroize;
+    core::slice::from_raw_parts_mut(ptr.cast::<core::mem::MaybeUninit<T>>(), len).zeroize();
+}
+
 fn range_covers_slice_start(start_bound: Bound<&usize>, slice_len: usize) -> (bool, bool) {
     match start_bound {
         Bound::Included(idx) => (*idx == 0, *idx > slice_len),
@@ -76,6 +129,50 @@ macro_rules! shared_impl {
         where
             $($tb : $trait,)?;
 
+        /// Guard giving mutable access to the wrapped vector, see `vec_mut_guard`.
+        ///
+        /// Dereferences to the wrapped vector. If the vector is empty once the
+        /// guard is dropped the element created by the fallback function is
+        /// pushed to it, so the length >= 1 constraint is upheld even if the
+        /// guard is dropped during unwinding.
+        ///
+        /// If the guard is leaked (e.g. using `mem::forget`) after emptying
+        /// the vector it stays empty, like when leaking a `Drain`.
+        $v struct VecMutGuard<'a, $t, F>
+        where
+            F: FnOnce() -> $item_ty,
+            $($tb : $trait,)?
+        {
+            vec1: &'a mut $name<$t>,
+            fallback: Option<F>,
+        }
+
+        /// Iterator returned by `extract_if`.
+        ///
+        /// Removes and yields all elements in the range for which the predicate
+        /// returns `true`, except for the last remaining element of the vector,
+        /// which is always kept (see [`ExtractIf::kept_element()`]).
+        ///
+        /// If the iterator is dropped before it is fully consumed the remaining
+        /// elements are kept. If it is leaked (e.g. using `mem::forget`) the vector
+        /// can lose elements and *might become empty*, like with `drain`.
+        $v struct ExtractIf<'a, $t, F>
+        where
+            $($tb : $trait,)?
+        {
+            vec: &'a mut $wrapped<$t>,
+            /// The index of the element which will be inspected next.
+            idx: usize,
+            /// The end of the range which is inspected.
+            end: usize,
+            /// The number of elements which were removed so far.
+            del: usize,
+            /// The original length of the vector.
+            old_len: usize,
+            pred: F,
+            kept_element: bool,
+        }
+
         const _: () = {
             use core::{
                 borrow::{Borrow, BorrowMut},
@@ -124,6 +221,40 @@ macro_rules! shared_impl {
                     $name($wrapped::from(vec))
                 }
 
+                /// Creates a new instance with `len` elements by calling `f` with the index of each element.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::Vec1;
+                /// # use std::num::NonZeroUsize;
+                /// let v = Vec1::from_fn(NonZeroUsize::new(3).unwrap(), |idx| idx * 2);
+                /// assert_eq!(v, [0, 2, 4]);
+                /// ```
+                pub fn from_fn<F>(len: NonZeroUsize, f: F) -> Self
+                where
+                    F: FnMut(usize) -> $item_ty
+                {
+                    $name((0..len.get()).map(f).collect())
+                }
+
+                /// Like [`Self::from_fn()`] but `f` can fail.
+                ///
+                /// # Errors
+                ///
+                /// Once any call to `f` returns a error that error is directly
+                /// returned by this method.
+                pub fn try_from_fn<F, E>(len: NonZeroUsize, mut f: F) -> Result<Self, E>
+                where
+                    F: FnMut(usize) -> Result<$item_ty, E>
+                {
+                    let mut vec = $wrapped::with_capacity(len.get());
+                    for idx in 0..len.get() {
+                        vec.push(f(idx)?);
+                    }
+                    Ok($name(vec))
+                }
+
                 /// Tries to create an instance from a normal `Vec<T>`.
                 ///
                 /// # Errors
@@ -313,17 +444,16 @@ macro_rules! shared_impl {
                 ///
                 /// # Panic Behavior
                 ///
-                /// The panic behavior is for now unspecified and might change without a
-                /// major version release.
-                ///
-                /// The current implementation does only delete non-retained elements at
-                /// the end of the `retain` function call. This might change in the future
-                /// matching `std`s behavior.
+                /// Like `Vec::retain` elements are visited in a single pass and non retained
+                /// elements are dropped the moment the predicate rejected them. If the
+                /// predicate (or the drop of a removed element) panics, all not yet visited
+                /// elements are kept and the vector is left in a consistent state which
+                /// still contains at least one element. No element is leaked or dropped twice.
                 ///
                 /// # Error
                 ///
-                /// If the last element would be removed instead of removing it a `Size0Error` is
-                /// returned.
+                /// If the predicate rejects all elements the last element is kept (after
+                /// the predicate was called on it) and a `Size0Error` is returned.
                 ///
                 /// # Example
                 ///
@@ -351,21 +481,20 @@ macro_rules! shared_impl {
                 /// The moment the last element would be removed this will instead fail, not removing
                 /// the element. **All other non retained elements will still be removed.** This means
                 /// you have to be more careful compared to `Vec::retain_mut` about how you modify
-                /// non retained elements in the closure.
+                /// the last element in the closure.
                 ///
                 /// # Panic Behavior
                 ///
-                /// The panic behavior is for now unspecified and might change without a
-                /// major version release.
-                ///
-                /// The current implementation does only delete non-retained elements at
-                /// the end of the `retain` function call. This might change in the future
-                /// matching `std`s behavior.
+                /// Like `Vec::retain` elements are visited in a single pass and non retained
+                /// elements are dropped the moment the predicate rejected them. If the
+                /// predicate (or the drop of a removed element) panics, all not yet visited
+                /// elements are kept and the vector is left in a consistent state which
+                /// still contains at least one element. No element is leaked or dropped twice.
                 ///
                 /// # Error
                 ///
-                /// If the last element would be removed instead of removing it a `Size0Error` is
-                /// returned.
+                /// If the predicate rejects all elements the last element is kept (after
+                /// the predicate was called on it) and a `Size0Error` is returned.
                 ///
                 /// # Example
                 ///
@@ -384,42 +513,131 @@ macro_rules! shared_impl {
                 /// assert_eq!(vec.len(), 1);
                 /// assert_eq!(vec.last(), &11);
                 /// ```
-                pub fn retain_mut<F>(&mut self, mut f: F) -> Result<(), Size0Error>
+                pub fn retain_mut<F>(&mut self, f: F) -> Result<(), Size0Error>
+                where
+                    F: FnMut(&mut $item_ty) -> bool
+                {
+                    if self.retain_in_place(false, f, None) {
+                        Err(Size0Error)
+                    } else {
+                        Ok(())
+                    }
+                }
+
+                /// Retains the elements matching the predicate in a single pass, keeping the capacity.
+                ///
+                /// If all elements are rejected the last element (or the first if `keep_first`
+                /// is `true`) is kept and `true` is returned. Removed elements are moved into
+                /// `removed` in their original order, or dropped if it is `None`.
+                fn retain_in_place<F>(
+                    &mut self,
+                    keep_first: bool,
+                    mut f: F,
+                    mut removed: Option<&mut Vec<$item_ty>>,
+                ) -> bool
                 where
                     F: FnMut(&mut $item_ty) -> bool
                 {
-                    // Code is based on the code in the standard library, but not the newest version
-                    // as the newest version uses unsafe optimizations.
-                    // Given a local instal of rust v1.50.0 source documentation in rustup:
-                    // <path-to-rustup-rust-v1.50.0-toolchain-with-source-doc>/share/doc/rust/html/src/alloc/vec.rs.html#1314-1334
-                    let len = self.len();
-                    let mut del = 0;
+                    // Code is based on the code in the standard library (`Vec::retain_mut` of rust v1.74),
+                    // extended to keep the first or last element if all elements are rejected.
+
+                    /// Moves the not yet processed elements over the hole of deleted elements and
+                    /// fixes the length, even if the predicate or a drop panics.
+                    struct BackshiftOnDrop<'a, $t>
+                    where
+                        $($tb : $trait,)?
                     {
-                        let v = &mut **self;
+                        v: &'a mut $wrapped<$t>,
+                        processed: usize,
+                        deleted: usize,
+                        original_len: usize,
+                    }
 
-                        for i in 0..len {
-                            if !f(&mut v[i]) {
-                                del += 1;
-                            } else if del > 0 {
-                                v.swap(i - del, i);
+                    impl<'a, $t> Drop for BackshiftOnDrop<'a, $t>
+                    where
+                        $($tb : $trait,)?
+                    {
+                        fn drop(&mut self) {
+                            if self.deleted > 0 {
+                                // SAFETY: Trailing unchecked items must be valid since we never touch them.
+                                unsafe {
+                                    let base = self.v.as_mut_ptr();
+                                    core::ptr::copy(
+                                        base.add(self.processed),
+                                        base.add(self.processed - self.deleted),
+                                        self.original_len - self.processed,
+                                    );
+                                }
+                            }
+                            // SAFETY: After filling holes, all items are in contiguous memory.
+                            unsafe {
+                                self.v.set_len(self.original_len - self.deleted);
                             }
                         }
                     }
-                    if del == 0 {
-                        Ok(())
-                    } else {
-                        if del < len {
-                            self.0.truncate(len - del);
-                            Ok(())
-                        } else {
-                            // if we would delete all then:
-                            // del == len AND no swap was done
-                            // so retain only last and return error
-                            self.swap(0, len - 1);
-                            self.0.truncate(1);
-                            Err(Size0Error)
+
+                    let original_len = self.len();
+                    // Avoid double drop if the drop guard is not executed,
+                    // since we may make some holes during the process.
+                    // SAFETY: all elements are still valid and owned by the guard below.
+                    unsafe { self.0.set_len(0) };
+
+                    let mut g = BackshiftOnDrop { v: &mut self.0, processed: 0, deleted: 0, original_len };
+                    let mut kept_fallback = false;
+
+                    while g.processed != original_len {
+                        let base = g.v.as_mut_ptr();
+                        // SAFETY: Unchecked element must be valid.
+                        let cur = unsafe { &mut *base.add(g.processed) };
+                        let mut retain = f(cur);
+                        if !retain && !keep_first && g.deleted + 1 == original_len {
+                            // this is the last element and all other elements were deleted
+                            retain = true;
+                            kept_fallback = true;
                         }
+                        if !retain && keep_first && g.processed == 0 {
+                            // keep the first element until we know another element is retained
+                            retain = true;
+                            kept_fallback = true;
+                        } else if retain && kept_fallback && keep_first {
+                            // another element is retained, so the kept first element is removed,
+                            // all elements between it and this element are holes already
+                            kept_fallback = false;
+                            g.deleted += 1;
+                            // SAFETY: The first element becomes a hole and is never touched again.
+                            let first = unsafe { core::ptr::read(base) };
+                            match removed.as_deref_mut() {
+                                Some(removed) => removed.insert(0, first),
+                                None => drop(first),
+                            }
+                        }
+                        if !retain {
+                            // Advance early to avoid double drop if the drop or push panicked.
+                            g.processed += 1;
+                            g.deleted += 1;
+                            // SAFETY: We never touch this element again after moving it out.
+                            let cur = unsafe { core::ptr::read(cur) };
+                            match removed.as_deref_mut() {
+                                Some(removed) => removed.push(cur),
+                                None => drop(cur),
+                            }
+                            continue;
+                        }
+                        if g.deleted > 0 {
+                            // SAFETY: `deleted` > 0, so the hole slot must not overlap with current element.
+                            // We use copy for move, and never touch this element again.
+                            unsafe {
+                                let hole_slot = base.add(g.processed - g.deleted);
+                                core::ptr::copy_nonoverlapping(cur, hole_slot, 1);
+                            }
+                        }
+                        g.processed += 1;
                     }
+
+                    // All items are processed, the guard fixes up the length.
+                    drop(g);
+
+                    kept_fallback
                 }
 
                 /// Calls `dedup_by_key` on the inner smallvec.
@@ -462,6 +680,277 @@ macro_rules! shared_impl {
                     }
                 }
 
+                /// Like [`pop()`](Self::pop) but the error records the failed [`Operation`](crate::Operation).
+                pub fn pop_with(&mut self) -> Result<$item_ty, crate::Size0ErrorWith> {
+                    self.pop().map_err(|_| crate::Size0ErrorWith::new(crate::Operation::Pop, ()))
+                }
+
+                /// Like [`remove()`](Self::remove) but the error records the failed [`Operation`](crate::Operation).
+                ///
+                /// # Panics
+                ///
+                /// Like `remove` panics if `index` is out of bounds.
+                pub fn remove_with(&mut self, index: usize) -> Result<$item_ty, crate::Size0ErrorWith> {
+                    self.remove(index)
+                        .map_err(|_| crate::Size0ErrorWith::new(crate::Operation::Remove, ()))
+                }
+
+                /// Like [`swap_remove()`](Self::swap_remove) but the error records the failed [`Operation`](crate::Operation).
+                ///
+                /// # Panics
+                ///
+                /// Like `swap_remove` panics if `index` is out of bounds.
+                pub fn swap_remove_with(&mut self, index: usize) -> Result<$item_ty, crate::Size0ErrorWith> {
+                    self.swap_remove(index)
+                        .map_err(|_| crate::Size0ErrorWith::new(crate::Operation::SwapRemove, ()))
+                }
+
+                /// Like [`truncate()`](Self::truncate) but the error records the failed [`Operation`](crate::Operation).
+                pub fn truncate_with(&mut self, len: usize) -> Result<(), crate::Size0ErrorWith> {
+                    self.truncate(len)
+                        .map_err(|_| crate::Size0ErrorWith::new(crate::Operation::Truncate, ()))
+                }
+
+                /// Like `retain` but keeps the last element if no element would be retained.
+                ///
+                /// Returns the removed elements in their original order.
+                ///
+                /// The elements are filtered in place in a single pass, so the capacity is
+                /// kept. If the predicate panics all not yet visited elements are kept, the
+                /// already removed elements are dropped.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3, 4];
+                /// assert_eq!(vec.retain_or_keep_last(|v| *v % 2 == 0), vec![1, 3]);
+                /// assert_eq!(vec, [2, 4]);
+                /// assert_eq!(vec.retain_or_keep_last(|_| false), vec![2]);
+                /// assert_eq!(vec, [4]);
+                /// ```
+                pub fn retain_or_keep_last<F>(&mut self, mut f: F) -> Vec<$item_ty>
+                where
+                    F: FnMut(&$item_ty) -> bool
+                {
+                    let mut removed = Vec::new();
+                    self.retain_in_place(false, |v| f(v), Some(&mut removed));
+                    removed
+                }
+
+                /// Like `retain` but keeps the first element if no element would be retained.
+                ///
+                /// Returns the removed elements in their original order.
+                ///
+                /// Like [`retain_or_keep_last()`](Self::retain_or_keep_last) this works
+                /// in place and keeps the capacity.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3];
+                /// assert_eq!(vec.retain_or_keep_first(|v| *v > 5), vec![2, 3]);
+                /// assert_eq!(vec, [1]);
+                /// ```
+                pub fn retain_or_keep_first<F>(&mut self, mut f: F) -> Vec<$item_ty>
+                where
+                    F: FnMut(&$item_ty) -> bool
+                {
+                    let mut removed = Vec::new();
+                    self.retain_in_place(true, |v| f(v), Some(&mut removed));
+                    removed
+                }
+
+                /// Removes the elements in `range`, keeping the first element if `range` covers all elements.
+                ///
+                /// Returns the removed elements.
+                ///
+                /// # Panic
+                ///
+                /// Like [`Vec::drain()`] panics if:
+                ///
+                /// - The starting point is greater than the end point.
+                /// - The end point is greater than the length of the vector.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3, 4];
+                /// assert_eq!(vec.drain_keep_one(1..3), vec![2, 3]);
+                /// assert_eq!(vec.drain_keep_one(..), vec![4]);
+                /// assert_eq!(vec, [1]);
+                /// ```
+                pub fn drain_keep_one<R>(&mut self, range: R) -> Vec<$item_ty>
+                where
+                    R: RangeBounds<usize>
+                {
+                    let (covers_all, out_of_bounds) = crate::shared::range_covers_slice(&range, self.len());
+                    if covers_all && !out_of_bounds {
+                        self.0.drain(1..).collect()
+                    } else {
+                        self.0.drain(range).collect()
+                    }
+                }
+
+                /// Truncates this vector to given length, but never to a length less than 1.
+                ///
+                /// Returns the removed elements.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3];
+                /// assert!(vec.truncate_saturating(5).is_empty());
+                /// assert_eq!(vec.truncate_saturating(0), vec![2, 3]);
+                /// assert_eq!(vec, [1]);
+                /// ```
+                pub fn truncate_saturating(&mut self, len: usize) -> Vec<$item_ty> {
+                    let len = len.max(1);
+                    if len < self.len() {
+                        self.0.drain(len..).collect()
+                    } else {
+                        Vec::new()
+                    }
+                }
+
+                /// Removes and yields all elements in `range` for which `filter` returns `true`.
+                ///
+                /// Like `Vec::extract_if` but the returned iterator never yields the last
+                /// remaining element of the vector. If `filter` matches all elements of the
+                /// vector the last one is kept instead, which can be checked using
+                /// [`ExtractIf::kept_element()`].
+                ///
+                /// Elements are only inspected while the iterator is consumed, elements which
+                /// were not inspected when the iterator is dropped are kept.
+                ///
+                /// # Panic
+                ///
+                /// If the range is out of bounds or its start is greater than its end.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3, 4, 5, 6];
+                /// let evens = vec.extract_if(.., |v| *v % 2 == 0).collect::<Vec<_>>();
+                /// assert_eq!(evens, [2, 4, 6]);
+                /// assert_eq!(vec, [1, 3, 5]);
+                ///
+                /// let mut iter = vec.extract_if(.., |_| true);
+                /// assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 3]);
+                /// assert!(iter.kept_element());
+                /// drop(iter);
+                /// assert_eq!(vec, [5]);
+                /// ```
+                pub fn extract_if<R, F>(&mut self, range: R, filter: F) -> ExtractIf<'_, $t, F>
+                where
+                    R: RangeBounds<usize>,
+                    F: FnMut(&mut $item_ty) -> bool,
+                {
+                    let old_len = self.len();
+                    let (start, end) = crate::shared::range_to_bounds(&range, old_len);
+                    // Guard against the vector getting leaked (leak amplification).
+                    // SAFETY: the `ExtractIf` restores the length when dropped.
+                    unsafe { self.0.set_len(0) };
+                    ExtractIf {
+                        vec: &mut self.0,
+                        idx: start,
+                        end,
+                        del: 0,
+                        old_len,
+                        pred: filter,
+                        kept_element: false,
+                    }
+                }
+
+                /// Replaces all elements with `first`, keeping the capacity.
+                ///
+                /// Returns the old elements as an iterator. Like with `drain`
+                /// the old elements are removed even if the iterator is not
+                /// fully consumed. As `first` replaces the old first element
+                /// in place, the vector never reallocates and still contains
+                /// `first` if the iterator is leaked.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3];
+                /// let old = vec.clear_to(10).collect::<Vec<_>>();
+                /// assert_eq!(old, [1, 2, 3]);
+                /// assert_eq!(vec, [10]);
+                /// ```
+                pub fn clear_to(
+                    &mut self,
+                    first: $item_ty,
+                ) -> core::iter::Chain<core::iter::Once<$item_ty>, Drain<'_, $t>> {
+                    let old = core::mem::replace(&mut self.0[0], first);
+                    core::iter::once(old).chain(self.0.drain(1..))
+                }
+
+                /// Replaces all elements with the elements of `other`.
+                ///
+                /// The old elements are removed before the new ones are added,
+                /// so this only reallocates if `other` is longer than the capacity.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3];
+                /// vec.replace_all(vec1![4, 5]);
+                /// assert_eq!(vec, [4, 5]);
+                /// ```
+                pub fn replace_all(&mut self, other: Self) {
+                    let mut iter = other.0.into_iter();
+                    //UNWRAP_SAFE: len is at least 1
+                    let first = iter.next().unwrap();
+                    self.replace_with_iter(first, iter);
+                }
+
+                /// Replaces all elements with `first` followed by the elements of `iter`.
+                ///
+                /// The old elements are removed before the new ones are added,
+                /// so this only reallocates if the new elements don't fit into
+                /// the capacity. If `iter` panics the vector contains `first` and
+                /// the elements yielded up to then.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3];
+                /// vec.replace_with_iter(7, 8..10);
+                /// assert_eq!(vec, [7, 8, 9]);
+                /// ```
+                pub fn replace_with_iter<IT>(&mut self, first: $item_ty, iter: IT)
+                where
+                    IT: IntoIterator<Item = $item_ty>,
+                {
+                    self.0.truncate(1);
+                    self.0[0] = first;
+                    self.0.extend(iter);
+                }
+
+                /// Removes all elements but the first one.
+                ///
+                /// Returns the removed elements.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3];
+                /// assert_eq!(vec.pop_all_but_first(), vec![2, 3]);
+                /// assert_eq!(vec, [1]);
+                /// ```
+                pub fn pop_all_but_first(&mut self) -> Vec<$item_ty> {
+                    self.truncate_saturating(1)
+                }
+
                 /// Remove the last element from this vector, if there is more than one element in it.
                 ///
                 /// # Errors
@@ -528,6 +1017,100 @@ macro_rules! shared_impl {
                     (smallvec, last)
                 }
 
+                /// Splits off the first element of this vector and returns it together with the
+                /// rest of the vector, which is `None` if it would be empty.
+                ///
+                /// This is useful for recursive algorithms working on non-empty vectors.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::{vec1, Vec1};
+                /// fn sum(vec: Vec1<u32>) -> u32 {
+                ///     match vec.split_into_first_and_vec1() {
+                ///         (first, Some(rest)) => first + sum(rest),
+                ///         (first, None) => first,
+                ///     }
+                /// }
+                /// assert_eq!(sum(vec1![1, 2, 3]), 6);
+                /// ```
+                pub fn split_into_first_and_vec1(self) -> ($item_ty, Option<Self>) {
+                    let (first, rest) = self.split_off_first();
+                    if rest.is_empty() {
+                        (first, None)
+                    } else {
+                        (first, Some($name(rest)))
+                    }
+                }
+
+                /// Splits this vector into two non-empty vectors at given index.
+                ///
+                /// The first vector contains the elements `[0, mid)`, the second
+                /// one the elements `[mid, len)`.
+                ///
+                /// # Errors
+                ///
+                /// If `mid` is `0` or `>= len` one of the vectors would be empty,
+                /// in which case a [`Size0ErrorWith`](crate::Size0ErrorWith) containing
+                /// this vector is returned.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let (left, right) = vec1![1, 2, 3].split_at_nonempty(1).unwrap();
+                /// assert_eq!(left, [1]);
+                /// assert_eq!(right, [2, 3]);
+                /// let err = vec1![1, 2, 3].split_at_nonempty(3).unwrap_err();
+                /// assert_eq!(err.into_input(), [1, 2, 3]);
+                /// ```
+                pub fn split_at_nonempty(self, mid: usize) -> Result<(Self, Self), crate::Size0ErrorWith<Self>> {
+                    if mid == 0 || mid >= self.len() {
+                        return Err(crate::Size0ErrorWith::new(crate::Operation::SplitAt, self));
+                    }
+                    let mut left = self.0;
+                    let right = left.drain(mid..).collect::<$wrapped<$t>>();
+                    Ok(($name(left), $name(right)))
+                }
+
+                /// Returns the first element and the rest of the elements.
+                ///
+                /// Like `<[T]>::split_first` but without the `Option` as there
+                /// always is a first element.
+                pub fn split_first_ref(&self) -> (&$item_ty, &[$item_ty]) {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.0.split_first().unwrap()
+                }
+
+                /// Returns the first element and the rest of the elements.
+                ///
+                /// Like `<[T]>::split_first_mut` but without the `Option` as there
+                /// always is a first element. (It's not named `split_first_mut` to
+                /// not shadow the slice method.)
+                pub fn split_first_ref_mut(&mut self) -> (&mut $item_ty, &mut [$item_ty]) {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.0.split_first_mut().unwrap()
+                }
+
+                /// Returns the last element and the rest of the elements.
+                ///
+                /// Like `<[T]>::split_last` but without the `Option` as there
+                /// always is a last element.
+                pub fn split_last_ref(&self) -> (&$item_ty, &[$item_ty]) {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.0.split_last().unwrap()
+                }
+
+                /// Returns the last element and the rest of the elements.
+                ///
+                /// Like `<[T]>::split_last_mut` but without the `Option` as there
+                /// always is a last element. (It's not named `split_last_mut` to
+                /// not shadow the slice method.)
+                pub fn split_last_ref_mut(&mut self) -> (&mut $item_ty, &mut [$item_ty]) {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.0.split_last_mut().unwrap()
+                }
+
                 /// Turns this vector into a boxed slice.
                 ///
                 /// For `Vec1` this is as cheap as for `Vec` but for
@@ -610,27 +1193,373 @@ macro_rules! shared_impl {
                     self.iter_mut().reduce(f).unwrap()
                 }
 
-            }
+                /// Alias for [`Self::reduce()`], under the name used by other crates.
+                #[inline]
+                pub fn fold1(self, f: impl FnMut($item_ty, $item_ty) -> $item_ty) -> $item_ty {
+                    self.reduce(f)
+                }
 
-            // methods in Vec not in &[] which can be directly exposed
-            impl_wrapper! {
-                base_bounds_macro = $($tb : $trait)?,
-                impl<$t> $name<$t> {
-                    fn append(&mut self, other: &mut $wrapped<$t>) -> ();
-                    fn reserve(&mut self, additional: usize) -> ();
-                    fn reserve_exact(&mut self, additional: usize) -> ();
-                    fn shrink_to_fit(&mut self) -> ();
-                    fn as_mut_slice(&mut self) -> &mut [$item_ty];
-                    fn push(&mut self, value: $item_ty) -> ();
-                    fn insert(&mut self, idx: usize, val: $item_ty) -> ();
-                    fn len(&self) -> usize;
-                    fn capacity(&self) -> usize;
-                    fn as_slice(&self) -> &[$item_ty];
+                /// Alias for [`Self::reduce_ref()`], under the name used by other crates.
+                #[inline]
+                pub fn fold1_ref<'a>(&'a self, f: impl FnMut(&'a $item_ty, &'a $item_ty) -> &'a $item_ty) -> &'a $item_ty {
+                    self.reduce_ref(f)
                 }
-            }
 
-            impl<$t> $name<$t>
-            where
+                /// Alias for [`Self::reduce_mut()`], under the name used by other crates.
+                #[inline]
+                pub fn fold1_mut<'a>(&'a mut self, f: impl FnMut(&'a mut $item_ty, &'a mut $item_ty) -> &'a mut $item_ty) -> &'a mut $item_ty {
+                    self.reduce_mut(f)
+                }
+
+                /// Sums up all elements.
+                ///
+                /// Unlike [`Iterator::sum()`] this only needs `Add` to be implemented,
+                /// as no identity element (zero) is needed for non-empty vectors.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![1, 2, 3].sum1(), 6);
+                /// ```
+                pub fn sum1(self) -> $item_ty
+                where
+                    $item_ty: core::ops::Add<Output = $item_ty>
+                {
+                    self.reduce(|acc, value| acc + value)
+                }
+
+                /// Multiplies all elements.
+                ///
+                /// Unlike [`Iterator::product()`] this only needs `Mul` to be implemented,
+                /// as no identity element (one) is needed for non-empty vectors.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![2, 3, 4].product1(), 24);
+                /// ```
+                pub fn product1(self) -> $item_ty
+                where
+                    $item_ty: core::ops::Mul<Output = $item_ty>
+                {
+                    self.reduce(|acc, value| acc * value)
+                }
+
+                /// Like `<[T]>::chunks()` but takes a [`NonZeroUsize`].
+                ///
+                /// As this vector is not empty the returned iterator yields
+                /// at least one chunk and every chunk has at least one element.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// # use std::num::NonZeroUsize;
+                /// let data = vec1![1, 2, 3, 4, 5];
+                /// let size = NonZeroUsize::new(2).unwrap();
+                /// let chunks = data.chunks1(size);
+                /// assert_eq!(chunks.first_chunk(), &[1, 2][..]);
+                /// let chunks = chunks.map(|chunk| chunk.as_slice()).collect::<Vec<_>>();
+                /// assert_eq!(chunks, vec![&[1, 2][..], &[3, 4], &[5]]);
+                /// ```
+                pub fn chunks1(&self, chunk_size: NonZeroUsize) -> crate::Chunks1<'_, $item_ty> {
+                    let first = &self[..chunk_size.get().min(self.len())];
+                    crate::Chunks1 {
+                        inner: self.chunks(chunk_size.get()),
+                        first: crate::Slice1::from_non_empty_slice(first),
+                    }
+                }
+
+                /// Like `<[T]>::rchunks()` but takes a [`NonZeroUsize`].
+                ///
+                /// As this vector is not empty the returned iterator yields
+                /// at least one chunk and every chunk has at least one element.
+                pub fn rchunks1(&self, chunk_size: NonZeroUsize) -> crate::RChunks1<'_, $item_ty> {
+                    let first = &self[self.len().saturating_sub(chunk_size.get())..];
+                    crate::RChunks1 {
+                        inner: self.rchunks(chunk_size.get()),
+                        first: crate::Slice1::from_non_empty_slice(first),
+                    }
+                }
+
+                /// Like `<[T]>::chunks_exact()` but takes a [`NonZeroUsize`].
+                ///
+                /// Every yielded chunk has at least one element, but if `chunk_size`
+                /// is greater than the length of this vector no chunk is yielded.
+                pub fn chunks_exact1(&self, chunk_size: NonZeroUsize) -> crate::ChunksExact1<'_, $item_ty> {
+                    crate::ChunksExact1 { inner: self.chunks_exact(chunk_size.get()) }
+                }
+
+                /// Like `<[T]>::windows()` but takes a [`NonZeroUsize`].
+                ///
+                /// Every yielded window has at least one element, but if `size`
+                /// is greater than the length of this vector no window is yielded.
+                pub fn windows1(&self, size: NonZeroUsize) -> crate::Windows1<'_, $item_ty> {
+                    crate::Windows1 { inner: self.windows(size.get()) }
+                }
+
+                /// Splits this vector into chunks of `chunk_size` elements.
+                ///
+                /// The last chunk has fewer elements if the length of this vector
+                /// isn't a multiple of `chunk_size`.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// # use std::num::NonZeroUsize;
+                /// let size = NonZeroUsize::new(2).unwrap();
+                /// let chunks = vec1![1, 2, 3, 4, 5].split_into_chunks(size);
+                /// assert_eq!(chunks, vec1![vec1![1, 2], vec1![3, 4], vec1![5]]);
+                /// ```
+                pub fn split_into_chunks(self, chunk_size: NonZeroUsize) -> crate::Vec1<$name<$t>> {
+                    let chunk_size = chunk_size.get();
+                    let mut chunks = Vec::with_capacity(self.len().div_ceil(chunk_size));
+                    let mut iter = self.into_iter();
+                    loop {
+                        let chunk: $wrapped<$t> = iter.by_ref().take(chunk_size).collect();
+                        if chunk.is_empty() {
+                            break;
+                        }
+                        chunks.push($name(chunk));
+                    }
+                    // len is at least 1 so there is at least one chunk
+                    crate::Vec1(chunks)
+                }
+
+                /// Calls `f` with mutable access to the wrapped vector.
+                ///
+                /// This allows using APIs which need a `&mut` of the wrapped
+                /// vector without converting this vector into it and back.
+                ///
+                /// # Errors
+                ///
+                /// If `f` leaves the vector empty the element returned by `fallback` is
+                /// pushed to it and a `Size0Error` is returned, the value returned by
+                /// `f` is dropped in that case. If `f` panics the vector is restored
+                /// the same way. `fallback` is only called if the vector is empty.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::{vec1, Size0Error};
+                /// let mut vec = vec1![1, 2, 3, 4];
+                /// let removed = vec.with_vec_mut(|| 0, |v| v.drain(1..3).count()).unwrap();
+                /// assert_eq!(removed, 2);
+                /// assert_eq!(vec, [1, 4]);
+                ///
+                /// assert_eq!(vec.with_vec_mut(|| 0, |v| v.clear()), Err(Size0Error));
+                /// assert_eq!(vec, [0]);
+                /// ```
+                pub fn with_vec_mut<FB, F, R>(&mut self, fallback: FB, f: F) -> Result<R, Size0Error>
+                where
+                    FB: FnOnce() -> $item_ty,
+                    F: FnOnce(&mut $wrapped<$t>) -> R
+                {
+                    let mut guard = self.vec_mut_guard(fallback);
+                    let res = f(&mut guard);
+                    guard.finish().map(|()| res)
+                }
+
+                /// Returns a guard giving mutable access to the wrapped vector.
+                ///
+                /// If the vector is empty once the guard is dropped the element
+                /// returned by `fallback` is pushed to it. Use [`VecMutGuard::finish()`]
+                /// to find out if that happened.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut vec = vec1![1, 2, 3];
+                /// {
+                ///     let mut guard = vec.vec_mut_guard(|| 10);
+                ///     guard.retain(|v| *v > 5);
+                /// }
+                /// assert_eq!(vec, [10]);
+                /// ```
+                pub fn vec_mut_guard<F>(&mut self, fallback: F) -> VecMutGuard<'_, $t, F>
+                where
+                    F: FnOnce() -> $item_ty,
+                {
+                    VecMutGuard { vec1: self, fallback: Some(fallback) }
+                }
+            }
+
+            impl<'a, $t, F> VecMutGuard<'a, $t, F>
+            where
+                F: FnOnce() -> $item_ty,
+                $($tb : $trait,)?
+            {
+                /// Drops the guard, reporting if the fallback element had to be used.
+                ///
+                /// # Errors
+                ///
+                /// If the vector was empty, in which case it now only contains
+                /// the fallback element.
+                pub fn finish(mut self) -> Result<(), Size0Error> {
+                    self.restore()
+                }
+
+                fn restore(&mut self) -> Result<(), Size0Error> {
+                    if self.vec1.0.is_empty() {
+                        if let Some(fallback) = self.fallback.take() {
+                            self.vec1.0.push(fallback());
+                        }
+                        Err(Size0Error)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+
+            impl<'a, $t, F> ExtractIf<'a, $t, F>
+            where
+                $($tb : $trait,)?
+            {
+                /// Returns `true` if an element was kept even through the predicate matched it.
+                ///
+                /// This happens if the predicate matched all elements of the vector, in which
+                /// case the last element is kept to uphold the length >= 1 constraint.
+                pub fn kept_element(&self) -> bool {
+                    self.kept_element
+                }
+            }
+
+            impl<'a, $t, F> Iterator for ExtractIf<'a, $t, F>
+            where
+                F: FnMut(&mut $item_ty) -> bool,
+                $($tb : $trait,)?
+            {
+                type Item = $item_ty;
+
+                // Code is based on `Vec::extract_if` of the standard library.
+                fn next(&mut self) -> Option<$item_ty> {
+                    // SAFETY: `old_len` elements are initialized, except for the `del`
+                    //         holes at `idx - del..idx` which are never read from `v`.
+                    unsafe {
+                        while self.idx < self.end {
+                            let i = self.idx;
+                            let v = core::slice::from_raw_parts_mut(self.vec.as_mut_ptr(), self.old_len);
+                            let mut drained = (self.pred)(&mut v[i]);
+                            if drained && self.del + 1 == self.old_len {
+                                // all other elements were removed, so keep this one
+                                drained = false;
+                                self.kept_element = true;
+                            }
+                            // Update the index *after* the predicate is called. If the index
+                            // is updated prior and the predicate panics, the element at this
+                            // index would be leaked.
+                            self.idx += 1;
+                            if drained {
+                                self.del += 1;
+                                return Some(core::ptr::read(&v[i]));
+                            } else if self.del > 0 {
+                                let del = self.del;
+                                let src: *const $item_ty = &v[i];
+                                let dst: *mut $item_ty = &mut v[i - del];
+                                core::ptr::copy_nonoverlapping(src, dst, 1);
+                            }
+                        }
+                        None
+                    }
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    (0, Some(self.end - self.idx))
+                }
+            }
+
+            impl<'a, $t, F> core::iter::FusedIterator for ExtractIf<'a, $t, F>
+            where
+                F: FnMut(&mut $item_ty) -> bool,
+                $($tb : $trait,)?
+            {}
+
+            impl<'a, $t, F> Drop for ExtractIf<'a, $t, F>
+            where
+                $($tb : $trait,)?
+            {
+                fn drop(&mut self) {
+                    // SAFETY: Moves the not inspected elements over the holes and then
+                    //         sets the length to the number of initialized elements.
+                    unsafe {
+                        if self.idx < self.old_len && self.del > 0 {
+                            let ptr = self.vec.as_mut_ptr();
+                            let src = ptr.add(self.idx);
+                            let dst = src.sub(self.del);
+                            let tail_len = self.old_len - self.idx;
+                            src.copy_to(dst, tail_len);
+                        }
+                        self.vec.set_len(self.old_len - self.del);
+                    }
+                }
+            }
+
+            impl<'a, $t, F> Deref for VecMutGuard<'a, $t, F>
+            where
+                F: FnOnce() -> $item_ty,
+                $($tb : $trait,)?
+            {
+                type Target = $wrapped<$t>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.vec1.0
+                }
+            }
+
+            impl<'a, $t, F> DerefMut for VecMutGuard<'a, $t, F>
+            where
+                F: FnOnce() -> $item_ty,
+                $($tb : $trait,)?
+            {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.vec1.0
+                }
+            }
+
+            impl<'a, $t, F> Drop for VecMutGuard<'a, $t, F>
+            where
+                F: FnOnce() -> $item_ty,
+                $($tb : $trait,)?
+            {
+                fn drop(&mut self) {
+                    let _ = self.restore();
+                }
+            }
+
+            impl<'a, $t, F> Debug for VecMutGuard<'a, $t, F>
+            where
+                F: FnOnce() -> $item_ty,
+                $item_ty: Debug,
+                $($tb : $trait,)?
+            {
+                fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+                    fter.debug_tuple("VecMutGuard").field(&self.vec1.0).finish()
+                }
+            }
+
+            // methods in Vec not in &[] which can be directly exposed
+            impl_wrapper! {
+                base_bounds_macro = $($tb : $trait)?,
+                impl<$t> $name<$t> {
+                    fn append(&mut self, other: &mut $wrapped<$t>) -> ();
+                    fn reserve(&mut self, additional: usize) -> ();
+                    fn reserve_exact(&mut self, additional: usize) -> ();
+                    fn shrink_to_fit(&mut self) -> ();
+                    fn as_mut_slice(&mut self) -> &mut [$item_ty];
+                    fn push(&mut self, value: $item_ty) -> ();
+                    fn insert(&mut self, idx: usize, val: $item_ty) -> ();
+                    fn len(&self) -> usize;
+                    fn capacity(&self) -> usize;
+                    fn as_slice(&self) -> &[$item_ty];
+                }
+            }
+
+            impl<$t> $name<$t>
+            where
                 $item_ty: PartialEq<$item_ty>,
                 $($tb : $trait,)?
             {
@@ -654,6 +1583,30 @@ macro_rules! shared_impl {
                 $item_ty: Clone,
                 $($tb : $trait,)?
             {
+                /// Creates a new instance containing `len` clones of `elem`.
+                pub fn from_elem(elem: $item_ty, len: NonZeroUsize) -> Self {
+                    let mut vec = $wrapped::with_capacity(len.get());
+                    vec.resize(len.get(), elem);
+                    $name(vec)
+                }
+
+                /// Creates a new instance by repeating this vector `n` times.
+                ///
+                /// Unlike `<[T]>::repeat()` this doesn't require `T: Copy` and
+                /// takes a [`NonZeroUsize`] so that the result can't be empty.
+                ///
+                /// # Panics
+                ///
+                /// If the capacity would overflow.
+                pub fn repeat1(&self, n: NonZeroUsize) -> Self {
+                    let capacity = self.len().checked_mul(n.get()).expect("capacity overflow");
+                    let mut vec = $wrapped::with_capacity(capacity);
+                    for _ in 0..n.get() {
+                        vec.extend(self.iter().cloned());
+                    }
+                    $name(vec)
+                }
+
                 /// See [`Vec::resize()`] but fails if it would resize to length 0.
                 pub fn resize(&mut self, len: usize, value: $item_ty) -> Result<(), Size0Error> {
                     if len == 0 {
@@ -969,12 +1922,370 @@ macro_rules! shared_impl {
                 }
             }
 
+            #[cfg(feature = "arbitrary")]
+            const _: () = {
+                use ::arbitrary::{size_hint, Arbitrary, MaxRecursionReached, Unstructured};
+
+                impl<'a, $t> Arbitrary<'a> for $name<$t>
+                where
+                    $item_ty: Arbitrary<'a>,
+                    $($tb : $trait,)?
+                {
+                    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                        let mut vec = $wrapped::new();
+                        vec.push(<$item_ty>::arbitrary(u)?);
+                        for item in u.arbitrary_iter()? {
+                            vec.push(item?);
+                        }
+                        Ok($name(vec))
+                    }
+
+                    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                        let mut vec = $wrapped::new();
+                        vec.push(<$item_ty>::arbitrary(&mut u)?);
+                        for item in u.arbitrary_take_rest_iter()? {
+                            vec.push(item?);
+                        }
+                        Ok($name(vec))
+                    }
+
+                    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                        Self::try_size_hint(depth).unwrap_or_default()
+                    }
+
+                    fn try_size_hint(depth: usize) -> Result<(usize, Option<usize>), MaxRecursionReached> {
+                        // the first element is always needed, after that it's like for `Vec`
+                        size_hint::try_recursion_guard(depth, |depth| {
+                            Ok(size_hint::and(<$item_ty>::try_size_hint(depth)?, (0, None)))
+                        })
+                    }
+                }
+            };
+
+            #[cfg(feature = "proptest")]
+            const _: () = {
+                use ::proptest::{arbitrary::{any_with, Arbitrary}, collection::SizeRange};
+                use crate::proptest_support::{at_least_one, Vec1Strategy};
+
+                /// A lower bound of 0 in the size range (e.g. the default `0..100`) is raised to 1.
+                impl<$t> Arbitrary for $name<$t>
+                where
+                    $item_ty: Arbitrary,
+                    $($tb : $trait,)?
+                {
+                    type Parameters = (SizeRange, <$item_ty as Arbitrary>::Parameters);
+                    type Strategy = Vec1Strategy<<$item_ty as Arbitrary>::Strategy, Self>;
+
+                    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+                        Vec1Strategy::new(any_with::<$item_ty>(params), at_least_one(size))
+                    }
+                }
+            };
+
+            #[cfg(feature = "quickcheck")]
+            const _: () = {
+                use alloc::boxed::Box;
+                use ::quickcheck::{Arbitrary, Gen};
+
+                impl<$t> Arbitrary for $name<$t>
+                where
+                    $item_ty: Arbitrary,
+                    $t: 'static,
+                    $($tb : $trait,)?
+                {
+                    /// Generates between 1 and `g.size()` elements.
+                    fn arbitrary(g: &mut Gen) -> Self {
+                        let len = usize::arbitrary(g) % g.size().max(1) + 1;
+                        let mut vec = $wrapped::with_capacity(len);
+                        for _ in 0..len {
+                            vec.push(<$item_ty>::arbitrary(g));
+                        }
+                        $name(vec)
+                    }
+
+                    /// Shrinks the length first and then the elements, never yielding an empty vector.
+                    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                        Box::new(
+                            self.to_vec()
+                                .shrink()
+                                .filter_map(|vec| Self::try_from(vec).ok()),
+                        )
+                    }
+                }
+            };
+
+            #[cfg(feature = "schemars")]
+            const _: () = {
+                use alloc::borrow::Cow;
+                use ::schemars::{JsonSchema, Schema, SchemaGenerator};
+
+                impl<$t> JsonSchema for $name<$t>
+                where
+                    $item_ty: JsonSchema,
+                    $($tb : $trait,)?
+                {
+                    fn inline_schema() -> bool {
+                        true
+                    }
+
+                    fn schema_name() -> Cow<'static, str> {
+                        crate::json_schema::nonempty_array_schema_name::<$item_ty>()
+                    }
+
+                    fn schema_id() -> Cow<'static, str> {
+                        crate::json_schema::nonempty_array_schema_id::<$item_ty>()
+                    }
+
+                    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+                        crate::json_schema::nonempty_array_schema::<$item_ty>(generator)
+                    }
+                }
+            };
+
+            #[cfg(feature = "zeroize")]
+            const _: () = {
+                use ::zeroize::Zeroize;
+                use crate::shared::zeroize_uninit;
+
+                /// Methods wiping the memory they free, if the elements contain secrets.
+                ///
+                /// Moving values (e.g. out of the vector or into a new allocation) can
+                /// still leave copies behind, e.g. on the stack.
+                impl<$t> $name<$t>
+                where
+                    $item_ty: Zeroize,
+                    $($tb : $trait,)?
+                {
+                    /// Wipes the memory of the (unused) slots `start..end`.
+                    fn zeroize_slots(&mut self, start: usize, end: usize) {
+                        debug_assert!(self.len() <= start && start <= end && end <= self.capacity());
+                        // Safety: The slots are within the capacity and don't hold live values.
+                        unsafe { zeroize_uninit(self.0.as_mut_ptr().add(start), end - start) }
+                    }
+
+                    /// Moves the elements into a new allocation with given capacity, wiping the old one.
+                    fn reallocate_and_zeroize(&mut self, capacity: usize) {
+                        let mut new = $wrapped::with_capacity(capacity);
+                        new.extend(self.0.drain(..));
+                        let old_capacity = self.capacity();
+                        self.zeroize_slots(0, old_capacity);
+                        self.0 = new;
+                    }
+
+                    /// Like `truncate` but zeroizes the removed elements before dropping them
+                    /// and wipes the memory they occupied.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If len is 0 an error is returned as the
+                    /// length >= 1 constraint must be uphold.
+                    pub fn truncate_and_zeroize(&mut self, len: usize) -> Result<(), Size0Error> {
+                        if len == 0 {
+                            return Err(Size0Error);
+                        }
+                        let old_len = self.len();
+                        if len < old_len {
+                            for item in &mut self.0[len..] {
+                                item.zeroize();
+                            }
+                            self.0.truncate(len);
+                            self.zeroize_slots(len, old_len);
+                        }
+                        Ok(())
+                    }
+
+                    /// Like `pop` but wipes the memory the popped element occupied.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If len is 1 an error is returned as the
+                    /// length >= 1 constraint must be uphold.
+                    pub fn pop_and_zeroize(&mut self) -> Result<$item_ty, Size0Error> {
+                        let value = self.pop()?;
+                        let len = self.len();
+                        self.zeroize_slots(len, len + 1);
+                        Ok(value)
+                    }
+
+                    /// Splits off the elements starting at `at` into a new vector and wipes the
+                    /// memory they occupied in this vector.
+                    ///
+                    /// # Panics
+                    ///
+                    /// If `at` is greater than `len`.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If splitting would result in an empty vector an error is returned, this happens
+                    /// if `at` is `0` or `at` is equals to `len`.
+                    pub fn split_off_and_zeroize(&mut self, at: usize) -> Result<Self, Size0Error> {
+                        let old_len = self.len();
+                        if at == 0 || at == old_len {
+                            return Err(Size0Error);
+                        }
+                        let tail = self.0.drain(at..).collect::<$wrapped<$t>>();
+                        self.zeroize_slots(at, old_len);
+                        Ok($name(tail))
+                    }
+
+                    /// Like `reserve` but wipes the old allocation if it has to be replaced.
+                    pub fn reserve_and_zeroize(&mut self, additional: usize) {
+                        let required = self.len().checked_add(additional).expect("capacity overflow");
+                        if required > self.capacity() {
+                            self.reallocate_and_zeroize(required.max(self.capacity() * 2));
+                        }
+                    }
+
+                    /// Like `shrink_to_fit` but wipes the old allocation if it is replaced.
+                    pub fn shrink_to_fit_and_zeroize(&mut self) {
+                        if self.capacity() > self.len() {
+                            let len = self.len();
+                            self.reallocate_and_zeroize(len);
+                        }
+                    }
+                }
+
+                impl<$t> Zeroize for $name<$t>
+                where
+                    $item_ty: Zeroize,
+                    $($tb : $trait,)?
+                {
+                    /// Zeroizes all elements, truncates the vector to its (zeroized) first
+                    /// element and wipes the whole spare capacity.
+                    fn zeroize(&mut self) {
+                        for item in self.0.iter_mut() {
+                            item.zeroize();
+                        }
+                        self.0.truncate(1);
+                        let len = self.len();
+                        let capacity = self.capacity();
+                        self.zeroize_slots(len, capacity);
+                    }
+                }
+            };
+
+            #[cfg(feature = "borsh")]
+            const _: () = {
+                use ::borsh::{
+                    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+                    BorshDeserialize, BorshSerialize,
+                };
+
+                // Like `Vec<T>`, forbid zero-sized types as their length can't be bounded by the input.
+                fn check_zst<T>() -> IoResult<()> {
+                    if core::mem::size_of::<T>() == 0 {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            "Collections of zero-sized types are not allowed due to deny-of-service concerns on deserialization.",
+                        ));
+                    }
+                    Ok(())
+                }
+
+                impl<$t> BorshSerialize for $name<$t>
+                where
+                    $item_ty: BorshSerialize,
+                    $($tb : $trait,)?
+                {
+                    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+                        check_zst::<$item_ty>()?;
+                        self.as_slice().serialize(writer)
+                    }
+                }
+
+                impl<$t> BorshDeserialize for $name<$t>
+                where
+                    $item_ty: BorshDeserialize,
+                    $($tb : $trait,)?
+                {
+                    fn deserialize_reader<R: Read>(reader: &mut R) -> IoResult<Self> {
+                        check_zst::<$item_ty>()?;
+                        let len = u32::deserialize_reader(reader)?;
+                        if len == 0 {
+                            return Err(Error::new(ErrorKind::InvalidData, Size0Error::MESSAGE));
+                        }
+                        let len = len as usize;
+                        let mut vec = $wrapped::with_capacity(crate::shared::cautious_capacity::<$item_ty>(len));
+                        for _ in 0..len {
+                            vec.push(<$item_ty>::deserialize_reader(reader)?);
+                        }
+                        Ok($name(vec))
+                    }
+                }
+            };
+
+            #[cfg(feature = "bincode")]
+            const _: () = {
+                use ::bincode::{
+                    de::{BorrowDecoder, Decoder},
+                    enc::Encoder,
+                    error::{DecodeError, EncodeError},
+                    BorrowDecode, Decode, Encode,
+                };
+
+                fn decode_len<D: Decoder>(decoder: &mut D) -> Result<usize, DecodeError> {
+                    let len = u64::decode(decoder)?;
+                    if len == 0 {
+                        return Err(DecodeError::Other(Size0Error::MESSAGE));
+                    }
+                    usize::try_from(len).map_err(|_| DecodeError::OutsideUsizeRange(len))
+                }
+
+                impl<$t> Encode for $name<$t>
+                where
+                    $item_ty: Encode,
+                    $($tb : $trait,)?
+                {
+                    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+                        self.as_slice().encode(encoder)
+                    }
+                }
+
+                impl<Context, $t> Decode<Context> for $name<$t>
+                where
+                    $item_ty: Decode<Context>,
+                    $($tb : $trait,)?
+                {
+                    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+                        let len = decode_len(decoder)?;
+                        decoder.claim_container_read::<$item_ty>(len)?;
+                        let mut vec = $wrapped::with_capacity(crate::shared::cautious_capacity::<$item_ty>(len));
+                        for _ in 0..len {
+                            // See the documentation of `Decoder::unclaim_bytes_read`.
+                            decoder.unclaim_bytes_read(core::mem::size_of::<$item_ty>());
+                            vec.push(<$item_ty>::decode(decoder)?);
+                        }
+                        Ok($name(vec))
+                    }
+                }
+
+                impl<'de, Context, $t> BorrowDecode<'de, Context> for $name<$t>
+                where
+                    $item_ty: BorrowDecode<'de, Context>,
+                    $($tb : $trait,)?
+                {
+                    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+                        decoder: &mut D,
+                    ) -> Result<Self, DecodeError> {
+                        let len = decode_len(decoder)?;
+                        decoder.claim_container_read::<$item_ty>(len)?;
+                        let mut vec = $wrapped::with_capacity(crate::shared::cautious_capacity::<$item_ty>(len));
+                        for _ in 0..len {
+                            // See the documentation of `Decoder::unclaim_bytes_read`.
+                            decoder.unclaim_bytes_read(core::mem::size_of::<$item_ty>());
+                            vec.push(<$item_ty>::borrow_decode(decoder)?);
+                        }
+                        Ok($name(vec))
+                    }
+                }
+            };
+
             //Note: We can not (simply) have if feature serde and feature smallvec enable
             //      dependency smallvec/serde, but we can mirror the serde implementation.
             #[cfg(feature = "serde")]
             const _: () = {
                 use core::marker::PhantomData;
-                use serde::{
+                use ::serde::{
                     de::{SeqAccess,Deserialize, Visitor, Deserializer, Error as _},
                     ser::{Serialize, Serializer, SerializeSeq}
                 };
@@ -1016,7 +2327,7 @@ macro_rules! shared_impl {
                     type Value = $name<$t>;
 
                     fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                        formatter.write_str("a sequence")
+                        formatter.write_str("a sequence with at least 1 element")
                     }
 
                     fn visit_seq<B>(self, mut seq: B) -> Result<Self::Value, B::Error>
@@ -1024,15 +2335,32 @@ macro_rules! shared_impl {
                         B: SeqAccess<'de>,
                     {
                         let len = seq.size_hint().unwrap_or(0);
-                        let mut vec = $wrapped::new();
-                        //FIXME use try_reserve
-                        vec.reserve(len);
+                        let mut vec = $wrapped::with_capacity(crate::shared::cautious_capacity::<$item_ty>(len));
 
                         while let Some(value) = seq.next_element()? {
                             vec.push(value);
                         }
 
-                        $name::try_from(vec).map_err(B::Error::custom)
+                        if vec.is_empty() {
+                            Err(B::Error::invalid_length(0, &self))
+                        } else {
+                            Ok($name(vec))
+                        }
+                    }
+                }
+
+                impl<$t> crate::serde_ext::Vec1Like for $name<$t>
+                where
+                    $($tb : $trait,)?
+                {
+                    type Item = $item_ty;
+
+                    fn try_from_vec(vec: Vec<$item_ty>) -> Result<Self, Size0Error> {
+                        $name::try_from_vec(vec)
+                    }
+
+                    fn as_slice(&self) -> &[$item_ty] {
+                        self
                     }
                 }
             };
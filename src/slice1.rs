@@ -0,0 +1,374 @@
+//! A non-empty slice type which can be created in `const` contexts.
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+use alloc::vec::Vec;
+
+use crate::{Size0Error, Vec1};
+
+/// Creates a `&'static Slice1<T>`, e.g. to be used in a `static` or `const`.
+///
+/// If it is called with less then 1 element a
+/// compiler error is triggered.
+///
+/// # Example
+///
+/// ```
+/// use vec1::{slice1, Slice1, Vec1};
+///
+/// static DEFAULT_SERVERS: &Slice1<&str> = slice1!["1.1.1.1", "8.8.8.8"];
+///
+/// assert_eq!(DEFAULT_SERVERS.first(), &"1.1.1.1");
+/// let servers: Vec1<&str> = DEFAULT_SERVERS.to_vec1();
+/// assert_eq!(servers.len(), 2);
+/// ```
+#[macro_export]
+macro_rules! slice1 {
+    () => (
+        compile_error!("Slice1 needs at least 1 element")
+    );
+    ($first:expr $(, $item:expr)* $(,)?) => (
+        $crate::Slice1::from_array_ref(&[$first $(, $item)*])
+    );
+}
+
+/// Forces a compiler error if `N == 0` once `OK` is used in a `const` context.
+struct AssertNonEmpty<const N: usize>;
+
+impl<const N: usize> AssertNonEmpty<N> {
+    const OK: () = assert!(N > 0, "Slice1 needs at least 1 element");
+}
+
+/// A `[T]` wrapper which guarantees to have at least 1 element.
+///
+/// This is the borrowed counterpart to [`Vec1`], like `[T]` is for `Vec<T>`.
+/// It can be created in `const` contexts (see [`Slice1::from_array_ref()`]
+/// and [`slice1!`]) and as such can be used for `static` default values.
+///
+/// `Slice1<T>` dereferences to `[T]`. Methods which return an `Option`
+/// on `[T]` only because the slice might be empty (e.g. `first`, `last`)
+/// are shadowed by methods directly returning the value.
+#[repr(transparent)]
+pub struct Slice1<T>([T]);
+
+impl<T> Slice1<T> {
+    /// Creates a `&Slice1<T>` from a reference to an array.
+    ///
+    /// The length of the array is checked at compile time,
+    /// i.e. using an empty array fails to compile.
+    ///
+    /// ```compile_fail
+    /// # use vec1::Slice1;
+    /// const EMPTY: &Slice1<u8> = Slice1::from_array_ref(&[]);
+    /// # let _ = EMPTY;
+    /// ```
+    pub const fn from_array_ref<const N: usize>(array: &[T; N]) -> &Self {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertNonEmpty::<N>::OK;
+        let slice: &[T] = array;
+        // SAFETY: Slice1 is a repr(transparent) wrapper around [T]
+        //         and the slice has at least one element.
+        unsafe { &*(slice as *const [T] as *const Slice1<T>) }
+    }
+
+    /// Tries to create a `&Slice1<T>` from a slice.
+    ///
+    /// # Errors
+    ///
+    /// If the slice is empty.
+    pub const fn try_from_slice(slice: &[T]) -> Result<&Self, Size0Error> {
+        if slice.is_empty() {
+            Err(Size0Error)
+        } else {
+            // SAFETY: Slice1 is a repr(transparent) wrapper around [T]
+            //         and the slice has at least one element.
+            Ok(unsafe { &*(slice as *const [T] as *const Slice1<T>) })
+        }
+    }
+
+    /// Creates a `&Slice1<T>` from a slice known to not be empty.
+    ///
+    /// Used for slices which are non-empty by construction, e.g. chunks.
+    pub(crate) fn from_non_empty_slice(slice: &[T]) -> &Self {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: Slice1 is a repr(transparent) wrapper around [T].
+        //         (An empty slice would only make e.g. `first` panic.)
+        unsafe { &*(slice as *const [T] as *const Slice1<T>) }
+    }
+
+    /// Creates a `&mut Slice1<T>` from a mutable slice known to not be empty.
+    pub(crate) fn from_non_empty_slice_mut(slice: &mut [T]) -> &mut Self {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: Slice1 is a repr(transparent) wrapper around [T].
+        unsafe { &mut *(slice as *mut [T] as *mut Slice1<T>) }
+    }
+
+    /// Tries to create a `&mut Slice1<T>` from a mutable slice.
+    ///
+    /// # Errors
+    ///
+    /// If the slice is empty.
+    pub fn try_from_slice_mut(slice: &mut [T]) -> Result<&mut Self, Size0Error> {
+        if slice.is_empty() {
+            Err(Size0Error)
+        } else {
+            // SAFETY: Slice1 is a repr(transparent) wrapper around [T]
+            //         and the slice has at least one element.
+            Ok(unsafe { &mut *(slice as *mut [T] as *mut Slice1<T>) })
+        }
+    }
+
+    /// Returns the wrapped slice.
+    pub const fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the wrapped slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    /// Returns the number of elements in the slice.
+    ///
+    /// Unlike `<[T]>::len()` this can be used in `const` contexts.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the len as a [`NonZeroUsize`]
+    pub const fn len_nonzero(&self) -> NonZeroUsize {
+        match NonZeroUsize::new(self.0.len()) {
+            Some(len) => len,
+            None => panic!("Slice1 is never empty"),
+        }
+    }
+
+    /// Returns a reference to the first element.
+    ///
+    /// As `Slice1` always contains at least one element there is always a first element.
+    pub const fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Returns a mutable reference to the first element.
+    ///
+    /// As `Slice1` always contains at least one element there is always a first element.
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.0[0]
+    }
+
+    /// Returns a reference to the last element.
+    ///
+    /// As `Slice1` always contains at least one element there is always a last element.
+    pub const fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Returns a mutable reference to the last element.
+    ///
+    /// As `Slice1` always contains at least one element there is always a last element.
+    pub fn last_mut(&mut self) -> &mut T {
+        let last = self.0.len() - 1;
+        &mut self.0[last]
+    }
+
+    /// Clones the elements into a new [`Vec1`].
+    pub fn to_vec1(&self) -> Vec1<T>
+    where
+        T: Clone,
+    {
+        Vec1(self.0.to_vec())
+    }
+}
+
+impl<T> Deref for Slice1<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Slice1<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<[T]> for Slice1<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> AsMut<[T]> for Slice1<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T> Borrow<[T]> for Slice1<T> {
+    fn borrow(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> fmt::Debug for Slice1<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl<A, B> PartialEq<Slice1<B>> for Slice1<A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &Slice1<B>) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<A, B> PartialEq<[B]> for Slice1<A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B]) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<T> Eq for Slice1<T> where T: Eq {}
+
+impl<T> Hash for Slice1<T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Slice1<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Slice1<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<'a, T> TryFrom<&'a [T]> for &'a Slice1<T> {
+    type Error = Size0Error;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        Slice1::try_from_slice(slice)
+    }
+}
+
+impl<'a, T> TryFrom<&'a mut [T]> for &'a mut Slice1<T> {
+    type Error = Size0Error;
+
+    fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
+        Slice1::try_from_slice_mut(slice)
+    }
+}
+
+impl<T> From<&Slice1<T>> for Vec1<T>
+where
+    T: Clone,
+{
+    fn from(slice: &Slice1<T>) -> Self {
+        slice.to_vec1()
+    }
+}
+
+impl<T> From<&Slice1<T>> for Vec<T>
+where
+    T: Clone,
+{
+    fn from(slice: &Slice1<T>) -> Self {
+        slice.0.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod Slice1 {
+        #![allow(non_snake_case)]
+        use crate::*;
+
+        static STATIC: &Slice1<&str> = slice1!["a", "b", "c"];
+        const CONST: &Slice1<u8> = Slice1::from_array_ref(&[4, 5]);
+        const CONST_FIRST: &u8 = CONST.first();
+
+        #[test]
+        fn usable_in_static_and_const() {
+            assert_eq!(STATIC.len(), 3);
+            assert_eq!(STATIC.first(), &"a");
+            assert_eq!(STATIC.last(), &"c");
+            assert_eq!(CONST.as_slice(), &[4, 5]);
+            assert_eq!(*CONST_FIRST, 4);
+        }
+
+        #[test]
+        fn slice1_macro() {
+            let a: &Slice1<u8> = slice1![1];
+            assert_eq!(a.as_slice(), &[1]);
+            let a: &Slice1<u8> = slice1![1, 2,];
+            assert_eq!(a.as_slice(), &[1, 2]);
+        }
+
+        #[test]
+        fn try_from_slice() {
+            let data = [1u8, 2];
+            let a = Slice1::try_from_slice(&data).unwrap();
+            assert_eq!(a.len_nonzero().get(), 2);
+            assert_eq!(Slice1::<u8>::try_from_slice(&[]), Err(Size0Error));
+        }
+
+        #[test]
+        fn try_from_slice_mut() {
+            let mut data = [1u8, 2];
+            let a = Slice1::try_from_slice_mut(&mut data).unwrap();
+            *a.first_mut() = 10;
+            *a.last_mut() = 20;
+            assert_eq!(data, [10, 20]);
+            Slice1::<u8>::try_from_slice_mut(&mut []).unwrap_err();
+        }
+
+        #[test]
+        fn to_vec1() {
+            let vec: Vec1<&str> = STATIC.to_vec1();
+            assert_eq!(vec, vec1!["a", "b", "c"]);
+            let vec = Vec1::from(CONST);
+            assert_eq!(vec, vec1![4u8, 5]);
+        }
+
+        #[test]
+        fn deref() {
+            assert!(STATIC.contains(&"b"));
+            assert_eq!(STATIC.iter().count(), 3);
+        }
+    }
+}
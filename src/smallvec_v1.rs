@@ -139,6 +139,43 @@ where
         &self.0
     }
 
+    /// Creates a `SmallVec1` from a `SmallVec` without checking that it's non-empty.
+    ///
+    /// In debug builds this still asserts that `wrapped` is not empty.
+    ///
+    /// # Safety
+    ///
+    /// `wrapped` must contain at least one element.
+    ///
+    /// An empty `SmallVec1` breaks the length >= 1 invariant all its methods are
+    /// written against, e.g. [`SmallVec1::first()`] and [`SmallVec1::last()`] panic and
+    /// other methods can return wrong results. It is not undefined behavior
+    /// by itself: no unsafe code in this crate relies on the length being at
+    /// least 1 (leaking e.g. a drain iterator can already leave the vector empty).
+    pub unsafe fn from_smallvec_unchecked(wrapped: SmallVec<A>) -> Self {
+        debug_assert!(
+            !wrapped.is_empty(),
+            "from_smallvec_unchecked called with empty smallvec"
+        );
+        Self(wrapped)
+    }
+
+    /// Return a mutable reference to the underlying `SmallVec`.
+    ///
+    /// Consider [`SmallVec1::with_vec_mut()`] for a safe alternative.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure the `SmallVec` contains at least one element
+    /// once the returned reference is no longer used, otherwise the length
+    /// >= 1 invariant of `SmallVec1` is broken (see [`SmallVec1::from_smallvec_unchecked()`]).
+    /// This includes not leaving the `SmallVec` empty if a panic happens while
+    /// it is mutated.
+    pub unsafe fn as_mut_smallvec(&mut self) -> &mut SmallVec<A> {
+        debug_assert!(!self.0.is_empty());
+        &mut self.0
+    }
+
     /// Converts this instance into a [`Vec<$item_ty>`] instance.
     pub fn into_vec(self) -> Vec<A::Item> {
         self.0.into_vec()
@@ -562,6 +599,19 @@ mod tests {
             assert_eq!(a, Err(Size0Error));
         }
 
+        #[test]
+        fn from_smallvec_unchecked() {
+            let a = unsafe { SmallVec1::<[u8; 4]>::from_smallvec_unchecked(smallvec![1, 2]) };
+            assert_eq!(a.as_slice(), &[1, 2]);
+        }
+
+        #[test]
+        fn as_mut_smallvec() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            unsafe { a.as_mut_smallvec() }.push(3);
+            assert_eq!(a.as_slice(), &[1, 2, 3]);
+        }
+
         #[test]
         fn try_from_buf() {
             let a = SmallVec1::try_from_buf([1u8, 2, 3, 4]);
@@ -796,6 +846,123 @@ mod tests {
             assert_eq!(a.pop(), Err(Size0Error));
         }
 
+        #[test]
+        fn retain_mut() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3, 4];
+            a.retain_mut(|v| {
+                *v += 1;
+                *v % 2 == 1
+            })
+            .unwrap();
+            assert_eq!(a.as_slice(), &[3, 5]);
+            assert_eq!(a.retain_mut(|_| false), Err(Size0Error));
+            assert_eq!(a.as_slice(), &[5]);
+
+            let mut a: SmallVec1<[u8; 2]> = smallvec1![1, 2, 3, 4, 5];
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                let _ = a.retain_mut(|v| if *v == 4 { panic!("fail") } else { *v == 1 });
+            }));
+            assert!(res.is_err());
+            assert_eq!(a.as_slice(), &[1, 4, 5]);
+        }
+
+        #[test]
+        fn retain_or_keep_last() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.retain_or_keep_last(|v| *v == 2), std::vec![1u8, 3]);
+            assert_eq!(a.retain_or_keep_last(|_| false), Vec::<u8>::new());
+            assert_eq!(a.as_slice(), &[2]);
+        }
+
+        #[test]
+        fn retain_or_keep_first() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.retain_or_keep_first(|_| false), std::vec![2u8, 3]);
+            assert_eq!(a.as_slice(), &[1]);
+
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.retain_or_keep_first(|v| *v == 3), std::vec![1u8, 2]);
+            assert_eq!(a.as_slice(), &[3]);
+        }
+
+        #[test]
+        fn drain_keep_one() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.drain_keep_one(..), std::vec![2u8, 3]);
+            assert_eq!(a.as_slice(), &[1]);
+        }
+
+        #[test]
+        fn truncate_saturating() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.truncate_saturating(2), std::vec![3u8]);
+            assert_eq!(a.truncate_saturating(0), std::vec![2u8]);
+            assert_eq!(a.as_slice(), &[1]);
+        }
+
+        #[test]
+        fn extract_if() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let extracted = a.extract_if(..2, |_| true).collect::<Vec<_>>();
+            assert_eq!(extracted, [1u8, 2]);
+            assert_eq!(a.as_slice(), &[3]);
+
+            let mut iter = a.extract_if(.., |_| true);
+            assert_eq!(iter.next(), None);
+            assert!(iter.kept_element());
+            drop(iter);
+            assert_eq!(a.as_slice(), &[3]);
+        }
+
+        #[test]
+        fn clear_to() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.clear_to(7).collect::<Vec<_>>(), [1u8, 2, 3]);
+            assert_eq!(a.as_slice(), &[7]);
+        }
+
+        #[test]
+        fn clear_to_leaked() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            core::mem::forget(a.clear_to(7));
+            assert_eq!(a.as_slice(), &[7]);
+        }
+
+        #[test]
+        fn replace_all() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            a.replace_all(smallvec1![4, 5]);
+            assert_eq!(a.as_slice(), &[4, 5]);
+        }
+
+        #[test]
+        fn replace_with_iter() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            a.replace_with_iter(4, [5, 6, 7]);
+            assert_eq!(a.as_slice(), &[4, 5, 6, 7]);
+            assert!(!a.spilled());
+        }
+
+        #[test]
+        fn operation_with_variants() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            assert_eq!(a.pop_with(), Ok(2));
+            let err = a.pop_with().unwrap_err();
+            assert_eq!(err.operation(), crate::Operation::Pop);
+            assert_eq!(Size0Error::from(err), Size0Error);
+            assert_eq!(
+                a.truncate_with(0).unwrap_err().operation(),
+                crate::Operation::Truncate
+            );
+        }
+
+        #[test]
+        fn pop_all_but_first() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.pop_all_but_first(), std::vec![2u8, 3]);
+            assert_eq!(a.as_slice(), &[1]);
+        }
+
         #[test]
         fn try_pop() {
             #![allow(deprecated)]
@@ -977,6 +1144,28 @@ mod tests {
             SmallVec1::<[u8; 4]>::try_from_elem(1u8, 0).unwrap_err();
         }
 
+        #[test]
+        fn from_elem() {
+            let a = SmallVec1::<[u8; 4]>::from_elem(1u8, NonZeroUsize::new(3).unwrap());
+            assert_eq!(a.as_slice(), &[1u8, 1, 1] as &[u8]);
+        }
+
+        #[test]
+        fn from_fn() {
+            let a = SmallVec1::<[u8; 4]>::from_fn(NonZeroUsize::new(3).unwrap(), |idx| idx as u8);
+            assert_eq!(a.as_slice(), &[0u8, 1, 2] as &[u8]);
+
+            let a = SmallVec1::<[u8; 4]>::try_from_fn(NonZeroUsize::new(3).unwrap(), |_| Err(()));
+            assert_eq!(a, Err(()));
+        }
+
+        #[test]
+        fn repeat1() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            let b = a.repeat1(NonZeroUsize::new(3).unwrap());
+            assert_eq!(b.as_slice(), &[1u8, 2, 1, 2, 1, 2] as &[u8]);
+        }
+
         #[test]
         fn split_off_first() {
             let a: SmallVec1<[u8; 4]> = smallvec1![32];
@@ -997,6 +1186,98 @@ mod tests {
             assert_eq!((exp, 43), a.split_off_last());
         }
 
+        #[test]
+        fn sum1() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.clone().sum1(), 6);
+            assert_eq!(a.clone().product1(), 6);
+            assert_eq!(a.fold1(core::cmp::max), 3);
+        }
+
+        #[test]
+        fn split_into_first_and_vec1() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            let (first, rest) = a.split_into_first_and_vec1();
+            assert_eq!(first, 1);
+            assert_eq!(rest.unwrap().as_slice(), &[2]);
+        }
+
+        #[test]
+        fn split_at_nonempty() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let a = a.split_at_nonempty(3).unwrap_err().into_input();
+            let (left, right) = a.split_at_nonempty(1).unwrap();
+            assert_eq!(left.as_slice(), &[1]);
+            assert_eq!(right.as_slice(), &[2, 3]);
+        }
+
+        #[test]
+        fn split_first_ref() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.split_first_ref(), (&1, &[2, 3][..]));
+            assert_eq!(a.split_last_ref(), (&3, &[1, 2][..]));
+            *a.split_first_ref_mut().0 = 4;
+            *a.split_last_ref_mut().0 = 5;
+            assert_eq!(a.as_slice(), &[4, 2, 5]);
+        }
+
+        #[test]
+        fn chunks1() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let size = NonZeroUsize::new(2).unwrap();
+            let chunks = a
+                .chunks1(size)
+                .map(|chunk| chunk.as_slice())
+                .collect::<Vec<_>>();
+            assert_eq!(chunks, vec![&[1u8, 2][..], &[3]]);
+        }
+
+        #[test]
+        fn with_vec_mut() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let res = a.with_vec_mut(|| 0, |v| v.drain(1..).count());
+            assert_eq!(res, Ok(2));
+            assert_eq!(a.as_slice(), &[1]);
+
+            let res = a.with_vec_mut(|| 4, |v| v.clear());
+            assert_eq!(res, Err(Size0Error));
+            assert_eq!(a.as_slice(), &[4]);
+        }
+
+        #[test]
+        fn vec_mut_guard() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            {
+                let mut guard = a.vec_mut_guard(|| 3);
+                guard.clear();
+            }
+            assert_eq!(a.as_slice(), &[3]);
+
+            let mut guard = a.vec_mut_guard(|| 0);
+            guard.push(4);
+            assert_eq!(guard.finish(), Ok(()));
+            assert_eq!(a.as_slice(), &[3, 4]);
+        }
+
+        #[test]
+        fn vec_mut_guard_leaked() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            let mut guard = a.vec_mut_guard(|| 3);
+            guard.push(4);
+            core::mem::forget(guard);
+            assert_eq!(a.as_slice(), &[1, 2, 4]);
+        }
+
+        #[test]
+        fn split_into_chunks() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let size = NonZeroUsize::new(2).unwrap();
+            let chunks = a.split_into_chunks(size);
+            let first: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            let second: SmallVec1<[u8; 4]> = smallvec1![3];
+            assert_eq!(chunks, crate::vec1![first, second]);
+        }
+
         #[test]
         fn from_vec_push() {
             let got: SmallVec1<[u8; 4]> = SmallVec1::from_vec_push(std::vec![], 1u8);
@@ -1048,6 +1329,123 @@ mod tests {
                 serde_json::from_str::<SmallVec1<[u8; 8]>>(&json_str).unwrap_err();
             }
         }
+
+        #[cfg(feature = "arbitrary")]
+        mod arbitrary {
+            use super::super::super::*;
+            use ::arbitrary::{Arbitrary, Unstructured};
+
+            #[test]
+            fn always_has_at_least_one_element() {
+                for len in 0..32u8 {
+                    let data = (0..len).map(|v| v.wrapping_mul(37)).collect::<Vec<_>>();
+                    let vec =
+                        SmallVec1::<[u8; 2]>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+                    assert!(!vec.is_empty());
+                    let vec = SmallVec1::<[u8; 2]>::arbitrary_take_rest(Unstructured::new(&data))
+                        .unwrap();
+                    assert!(!vec.is_empty());
+                }
+            }
+
+            #[test]
+            fn size_hint() {
+                assert_eq!(SmallVec1::<[u16; 2]>::size_hint(0), (2, None));
+            }
+        }
+
+        #[cfg(feature = "borsh")]
+        mod borsh {
+            use super::super::super::*;
+
+            #[test]
+            fn roundtrip() {
+                let vec = SmallVec1::<[u16; 2]>::try_from_vec(std::vec![1, 2, 3]).unwrap();
+                let bytes = ::borsh::to_vec(&vec).unwrap();
+                let decoded = ::borsh::from_slice::<SmallVec1<[u16; 2]>>(&bytes).unwrap();
+                assert_eq!(decoded.as_slice(), &[1, 2, 3]);
+                assert!(::borsh::from_slice::<SmallVec1<[u16; 2]>>(&[0, 0, 0, 0]).is_err());
+            }
+        }
+
+        #[cfg(feature = "bincode")]
+        mod bincode {
+            use super::super::super::*;
+            use ::bincode::config;
+
+            #[test]
+            fn roundtrip() {
+                let vec = SmallVec1::<[u16; 2]>::try_from_vec(std::vec![1, 2, 3]).unwrap();
+                let bytes = ::bincode::encode_to_vec(&vec, config::standard()).unwrap();
+                let (decoded, _) = ::bincode::decode_from_slice::<SmallVec1<[u16; 2]>, _>(
+                    &bytes,
+                    config::standard(),
+                )
+                .unwrap();
+                assert_eq!(decoded.as_slice(), &[1, 2, 3]);
+                assert!(::bincode::decode_from_slice::<SmallVec1<[u16; 2]>, _>(
+                    &[0],
+                    config::standard()
+                )
+                .is_err());
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        mod zeroize {
+            use super::super::super::*;
+            use crate::zeroize_support::tests::allocation;
+            use ::zeroize::Zeroize;
+
+            #[test]
+            fn zeroize_wipes_inline_spare_capacity() {
+                let mut vec = SmallVec1::<[u8; 4]>::try_from_vec(std::vec![7; 4]).unwrap();
+                vec.zeroize();
+                assert_eq!(vec.as_slice(), &[0]);
+                assert_eq!(allocation(&vec, vec.capacity()), &[0; 4]);
+            }
+
+            #[test]
+            fn wiping_methods() {
+                let mut vec = SmallVec1::<[u8; 4]>::try_from_vec(std::vec![7; 4]).unwrap();
+                assert_eq!(vec.pop_and_zeroize(), Ok(7));
+                vec.truncate_and_zeroize(2).unwrap();
+                assert_eq!(allocation(&vec, vec.capacity()), &[7, 7, 0, 0]);
+                let tail = vec.split_off_and_zeroize(1).unwrap();
+                assert_eq!(tail.as_slice(), &[7]);
+                assert_eq!(allocation(&vec, vec.capacity()), &[7, 0, 0, 0]);
+
+                vec.reserve_and_zeroize(8);
+                assert!(vec.spilled());
+                vec.shrink_to_fit_and_zeroize();
+                assert!(!vec.spilled());
+                assert_eq!(vec.as_slice(), &[7]);
+            }
+        }
+
+        #[cfg(feature = "quickcheck")]
+        mod quickcheck {
+            use super::super::super::*;
+            use ::quickcheck::{Arbitrary, Gen};
+
+            #[test]
+            fn respects_gen_size() {
+                let mut g = Gen::new(3);
+                for _ in 0..100 {
+                    let vec = SmallVec1::<[u8; 2]>::arbitrary(&mut g);
+                    assert!((1..=3).contains(&vec.len()));
+                }
+            }
+
+            #[test]
+            fn never_shrinks_to_empty() {
+                let vec = SmallVec1::<[u8; 2]>::try_from_vec(std::vec![3, 4, 5]).unwrap();
+                let shrunk = vec.shrink().collect::<Vec<_>>();
+                assert!(!shrunk.is_empty());
+                assert!(shrunk.iter().all(|vec| !vec.is_empty()));
+                assert_eq!(shrunk[0].len(), 2);
+            }
+        }
     }
 
     mod macros {
@@ -0,0 +1,235 @@
+//! Support for wiping secrets with `zeroize`, see the `zeroize` feature.
+
+use core::{fmt, mem::ManuallyDrop, ops::Deref, ptr};
+
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Size0Error, Vec1};
+
+/// A [`Vec1`] which is zeroized when dropped.
+///
+/// Unlike `zeroize::Zeroizing<Vec1<T>>` this only gives mutable access through
+/// methods which wipe the memory they free or replace, e.g. [`push`](ZeroizingVec1::push)
+/// wipes the old allocation if it has to grow the vector.
+///
+/// ```
+/// use vec1::{vec1, zeroize_support::ZeroizingVec1};
+///
+/// let mut keys = ZeroizingVec1::new(vec1![[1u8; 32]]);
+/// keys.push([2; 32]);
+/// assert_eq!(keys.pop(), Ok([2; 32]));
+/// assert_eq!(keys.len(), 1);
+/// ```
+pub struct ZeroizingVec1<T>(Vec1<T>)
+where
+    T: Zeroize;
+
+impl<T> ZeroizingVec1<T>
+where
+    T: Zeroize,
+{
+    /// Wraps the given vector.
+    pub fn new(vec: Vec1<T>) -> Self {
+        ZeroizingVec1(vec)
+    }
+
+    /// Returns the wrapped vector without zeroizing it.
+    pub fn into_inner(self) -> Vec1<T> {
+        let this = ManuallyDrop::new(self);
+        // Safety: `this` is never dropped, so the vector is moved out exactly once.
+        unsafe { ptr::read(&this.0) }
+    }
+
+    /// Returns a mutable slice of the elements.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.0.as_mut_slice()
+    }
+
+    /// Appends an element, wiping the old allocation if it has to grow.
+    pub fn push(&mut self, value: T) {
+        self.0.reserve_and_zeroize(1);
+        self.0.push(value);
+    }
+
+    /// See [`Vec1::pop_and_zeroize()`].
+    pub fn pop(&mut self) -> Result<T, Size0Error> {
+        self.0.pop_and_zeroize()
+    }
+
+    /// See [`Vec1::truncate_and_zeroize()`].
+    pub fn truncate(&mut self, len: usize) -> Result<(), Size0Error> {
+        self.0.truncate_and_zeroize(len)
+    }
+
+    /// See [`Vec1::split_off_and_zeroize()`].
+    pub fn split_off(&mut self, at: usize) -> Result<Self, Size0Error> {
+        self.0.split_off_and_zeroize(at).map(ZeroizingVec1)
+    }
+
+    /// See [`Vec1::reserve_and_zeroize()`].
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve_and_zeroize(additional)
+    }
+
+    /// See [`Vec1::shrink_to_fit_and_zeroize()`].
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit_and_zeroize()
+    }
+}
+
+impl<T> Deref for ZeroizingVec1<T>
+where
+    T: Zeroize,
+{
+    type Target = Vec1<T>;
+
+    fn deref(&self) -> &Vec1<T> {
+        &self.0
+    }
+}
+
+impl<T> From<Vec1<T>> for ZeroizingVec1<T>
+where
+    T: Zeroize,
+{
+    fn from(vec: Vec1<T>) -> Self {
+        ZeroizingVec1(vec)
+    }
+}
+
+impl<T> Clone for ZeroizingVec1<T>
+where
+    T: Zeroize + Clone,
+{
+    fn clone(&self) -> Self {
+        ZeroizingVec1(self.0.clone())
+    }
+}
+
+impl<T> fmt::Debug for ZeroizingVec1<T>
+where
+    T: Zeroize + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ZeroizingVec1").field(&self.0).finish()
+    }
+}
+
+impl<T> Zeroize for ZeroizingVec1<T>
+where
+    T: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl<T> Drop for ZeroizingVec1<T>
+where
+    T: Zeroize,
+{
+    fn drop(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl<T> ZeroizeOnDrop for ZeroizingVec1<T> where T: Zeroize {}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::vec1;
+
+    /// Returns the whole allocation of a vector, including spare capacity, as bytes.
+    ///
+    /// Shared with the `SmallVec1` tests.
+    pub(crate) fn allocation(elements: &[u8], capacity: usize) -> &[u8] {
+        // Safety: All bytes of the allocation were initialized by the tests before.
+        unsafe { core::slice::from_raw_parts(elements.as_ptr(), capacity) }
+    }
+
+    fn filled(len: usize, capacity: usize) -> Vec1<u8> {
+        let mut vec = Vec1::with_capacity(0xff, capacity);
+        vec.resize(capacity, 0xff).unwrap();
+        vec.truncate(len).unwrap();
+        vec
+    }
+
+    #[test]
+    fn zeroize_wipes_spare_capacity() {
+        let mut vec = filled(3, 8);
+        vec.zeroize();
+        assert_eq!(vec, [0]);
+        assert_eq!(allocation(&vec, vec.capacity()), &[0; 8]);
+    }
+
+    #[test]
+    fn zeroize_elements() {
+        let mut vec = vec1![std::vec![1u8, 2], std::vec![3]];
+        vec.zeroize();
+        assert_eq!(vec.len(), 1);
+        assert!(vec[0].is_empty());
+    }
+
+    #[test]
+    fn truncate_and_zeroize() {
+        let mut vec = filled(6, 6);
+        assert_eq!(vec.truncate_and_zeroize(0), Err(Size0Error));
+        vec.truncate_and_zeroize(2).unwrap();
+        assert_eq!(vec, [0xff, 0xff]);
+        assert_eq!(allocation(&vec, vec.capacity()), &[0xff, 0xff, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn pop_and_zeroize() {
+        let mut vec = filled(3, 3);
+        assert_eq!(vec.pop_and_zeroize(), Ok(0xff));
+        assert_eq!(allocation(&vec, vec.capacity()), &[0xff, 0xff, 0]);
+        vec.pop_and_zeroize().unwrap();
+        assert_eq!(vec.pop_and_zeroize(), Err(Size0Error));
+        assert_eq!(allocation(&vec, vec.capacity()), &[0xff, 0, 0]);
+    }
+
+    #[test]
+    fn split_off_and_zeroize() {
+        let mut vec = filled(4, 4);
+        assert_eq!(vec.split_off_and_zeroize(0), Err(Size0Error));
+        assert_eq!(vec.split_off_and_zeroize(4), Err(Size0Error));
+        let tail = vec.split_off_and_zeroize(1).unwrap();
+        assert_eq!(tail, [0xff, 0xff, 0xff]);
+        assert_eq!(allocation(&vec, vec.capacity()), &[0xff, 0, 0, 0]);
+    }
+
+    #[test]
+    fn shrink_to_fit_and_reserve_and_zeroize() {
+        let mut vec = filled(2, 8);
+        vec.zeroize();
+        vec.push(1);
+        vec.shrink_to_fit_and_zeroize();
+        assert_eq!(vec, [0, 1]);
+        assert_eq!(vec.capacity(), 2);
+        vec.reserve_and_zeroize(10);
+        assert!(vec.capacity() >= 12);
+        assert_eq!(vec, [0, 1]);
+    }
+
+    #[test]
+    fn zeroizing_vec1() {
+        let mut keys = ZeroizingVec1::new(vec1![[1u8; 4]]);
+        for i in 2..20 {
+            keys.push([i; 4]);
+        }
+        assert_eq!(keys.len(), 19);
+        keys.truncate(10).unwrap();
+        let tail = keys.split_off(5).unwrap();
+        assert_eq!(tail.len(), 5);
+        assert_eq!(keys.pop(), Ok([5; 4]));
+        keys.as_mut_slice()[0] = [0; 4];
+        keys.shrink_to_fit();
+        assert_eq!(keys.capacity(), 4);
+        assert_eq!(*keys.into_inner().last(), [4; 4]);
+
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<ZeroizingVec1<u8>>();
+    }
+}
@@ -17,3 +17,9 @@ fn allow_no_trailing_comma_in_vec_macro() {
     let _ = vec1![1u8, 2u8];
     let _ = vec1![1u8, 2u8, 3u8];
 }
+
+#[test]
+fn allow_trailing_comma_in_with_capacity_vec_macro() {
+    let _ = vec1![with_capacity = 4; 1u8,];
+    let _ = vec1![with_capacity = 4; 1u8, 2u8,];
+}